@@ -1,5 +1,7 @@
 use std::f64::consts::PI;
-use crate::utils::utils::close_equal;
+use crate::geometry::angle::types::{AngleRange, Radians};
+use crate::geometry::types::types::Orientation;
+use crate::utils::utils::{close_equal, Tolerance};
 
 /**
  * Verify that a number is not negative zero, if so then replace it.
@@ -9,12 +11,109 @@ pub fn no_negative_zero(x: f64) -> f64 {
 }
 
 /**
- * Ensure that no negative π or negative zero.
+ * Ensure that no negative π or negative zero. Angles at -π are turned to π,
+ * so that results lie in (-π, π] and keep pointing the same way.
  */
 pub fn correct_angle_signs(x: f64) -> f64 {
-  if close_equal(x, -1.0 * PI, None) {
-    x + PI
+  if close_equal(x, -PI, None) {
+    x + 2.0 * PI
   } else {
     no_negative_zero(x)
   }
-}
\ No newline at end of file
+}
+
+/**
+ * Ensure that no negative π or negative zero, treating angles within the
+ * tolerance of -π as -π. The relative part of the tolerance scales with π.
+ */
+pub fn correct_angle_signs_within(x: f64, tolerance: impl Into<Tolerance>) -> f64 {
+  if close_equal(x, -PI, tolerance) {
+    x + 2.0 * PI
+  } else {
+    no_negative_zero(x)
+  }
+}
+
+/**
+ * Normalize an angle to [0, 2π).
+ */
+pub fn normalize_angle(x: f64) -> f64 {
+  let angle = x.rem_euclid(2.0 * PI);
+  // rem_euclid can round up to exactly 2π for tiny negative inputs.
+  if angle >= 2.0 * PI { 0.0 } else { no_negative_zero(angle) }
+}
+
+/**
+ * Normalize an angle to (-π, π].
+ */
+pub fn normalize_angle_signed(x: f64) -> f64 {
+  let angle = normalize_angle(x);
+  if angle > PI { angle - 2.0 * PI } else { angle }
+}
+
+/**
+ * Shortest signed turn from one angle to another, in (-π, π]. Positive
+ * differences turn counter-clockwise.
+ */
+pub fn angle_difference(from: f64, to: f64) -> f64 {
+  normalize_angle_signed(to - from)
+}
+
+/**
+ * Counter-clockwise turn from one angle to another, in [0, 2π).
+ */
+pub fn ccw_angle_difference(from: f64, to: f64) -> f64 {
+  normalize_angle(to - from)
+}
+
+/**
+ * Create the range turning from one angle to another in the given direction.
+ * Equal angles give an empty range rather than a full turn.
+ */
+pub fn angle_range_between(start: impl Into<Radians>, end: impl Into<Radians>, direction: Orientation) -> AngleRange {
+  let (start, end) = (start.into().0, end.into().0);
+  let sweep = ccw_angle_difference(start, end);
+  AngleRange {
+    start,
+    sweep: match direction {
+      Orientation::Clockwise if sweep != 0.0 => sweep - 2.0 * PI,
+      _ => sweep,
+    },
+  }
+}
+
+/**
+ * Angle at which a range ends.
+ */
+pub fn angle_range_end(range: &AngleRange) -> f64 {
+  range.start + range.sweep
+}
+
+/**
+ * Turn from the start of a range, in its direction, to reach an angle, in [0, 2π).
+ */
+pub fn angle_range_offset(range: &AngleRange, angle: impl Into<Radians>) -> f64 {
+  let angle = angle.into().0;
+  if range.sweep < 0.0 {
+    ccw_angle_difference(angle, range.start)
+  } else {
+    ccw_angle_difference(range.start, angle)
+  }
+}
+
+/**
+ * Determine if an angle lies within a range, allowing it to fall outside
+ * either end by up to the tolerance.
+ */
+pub fn angle_range_contains(range: &AngleRange, angle: impl Into<Radians>, tolerance: impl Into<Tolerance>) -> bool {
+  let angle = angle.into().0;
+  let tolerance = tolerance.into();
+  let sweep = range.sweep.abs();
+  if sweep >= 2.0 * PI {
+    return true;
+  }
+
+  let offset = angle_range_offset(range, angle);
+  // Angles just before the start wrap around to nearly 2π.
+  offset <= sweep || close_equal(offset, sweep, tolerance) || close_equal(offset, 2.0 * PI, tolerance)
+}
@@ -0,0 +1,230 @@
+use std::f64::consts::PI;
+use crate::geometry::angle::angle::normalize_angle;
+use crate::geometry::angle::types::{Bearing, Degrees, EastWest, NorthSouth, Radians, TraverseLeg};
+use crate::geometry::point::point::p2p_angle;
+use crate::geometry::types::error::GeometryError;
+use crate::geometry::types::stroke_types::Segment;
+use crate::geometry::types::types::XY;
+
+/**
+ * Normalize an azimuth to [0°, 360°).
+ */
+pub fn normalize_azimuth(azimuth: Degrees) -> Degrees {
+  let degrees = azimuth.0.rem_euclid(360.0);
+  // rem_euclid can round up to exactly 360° for tiny negative inputs.
+  Degrees(if degrees >= 360.0 { 0.0 } else { degrees })
+}
+
+/**
+ * Convert an azimuth, clockwise from north, to a polar angle in radians,
+ * counter-clockwise from +x, in [0, 2π).
+ */
+pub fn azimuth_to_angle(azimuth: Degrees) -> f64 {
+  normalize_angle(PI / 2.0 - azimuth.0.to_radians())
+}
+
+/**
+ * Convert a polar angle in radians, counter-clockwise from +x, to an azimuth
+ * clockwise from north.
+ */
+pub fn angle_to_azimuth(angle: impl Into<Radians>) -> Degrees {
+  normalize_azimuth(Degrees((PI / 2.0 - angle.into().0).to_degrees()))
+}
+
+/**
+ * Azimuth of the direction from one point to another.
+ */
+pub fn p2p_azimuth(p1: XY, p2: XY) -> Degrees {
+  angle_to_azimuth(p2p_angle(p1, p2))
+}
+
+pub fn bearing_to_azimuth(bearing: &Bearing) -> Degrees {
+  let angle = bearing.angle.0;
+  normalize_azimuth(Degrees(match (bearing.from, bearing.towards) {
+    (NorthSouth::North, EastWest::East) => angle,
+    (NorthSouth::South, EastWest::East) => 180.0 - angle,
+    (NorthSouth::South, EastWest::West) => 180.0 + angle,
+    (NorthSouth::North, EastWest::West) => 360.0 - angle,
+  }))
+}
+
+/**
+ * Convert an azimuth to a compass bearing. Due east and west are given from
+ * north, and due south is S0°E.
+ */
+pub fn azimuth_to_bearing(azimuth: Degrees) -> Bearing {
+  let azimuth = normalize_azimuth(azimuth).0;
+  let (from, angle, towards) = if azimuth <= 90.0 {
+    (NorthSouth::North, azimuth, EastWest::East)
+  } else if azimuth <= 180.0 {
+    (NorthSouth::South, 180.0 - azimuth, EastWest::East)
+  } else if azimuth < 270.0 {
+    (NorthSouth::South, azimuth - 180.0, EastWest::West)
+  } else {
+    (NorthSouth::North, 360.0 - azimuth, EastWest::West)
+  };
+
+  Bearing { from, angle: Degrees(angle), towards }
+}
+
+/**
+ * Point reached by travelling a distance from a start point along an azimuth.
+ */
+pub fn point_at_azimuth(start: XY, azimuth: Degrees, distance: f64) -> XY {
+  let angle = azimuth_to_angle(azimuth);
+  XY {
+    x: start.x + distance * angle.cos(),
+    y: start.y + distance * angle.sin(),
+  }
+}
+
+/**
+ * Point reached by travelling a distance from a start point along a bearing.
+ */
+pub fn point_at_bearing(start: XY, bearing: &Bearing, distance: f64) -> XY {
+  point_at_azimuth(start, bearing_to_azimuth(bearing), distance)
+}
+
+/**
+ * Connected segments walking a traverse from a start point, one per leg.
+ */
+pub fn traverse_segments(start: XY, legs: &[TraverseLeg]) -> Vec<Segment> {
+  let mut p1 = start;
+  legs.iter().map(|leg| {
+    let p2 = point_at_azimuth(p1, leg.azimuth, leg.distance);
+    let segment = Segment { p1, p2 };
+    p1 = p2;
+    segment
+  }).collect()
+}
+
+fn dms_unit(c: char) -> Option<usize> {
+  match c {
+    '°' | 'º' | 'd' | 'D' => Some(0),
+    '\'' | '′' | 'm' | 'M' => Some(1),
+    '"' | '″' | 's' | 'S' => Some(2),
+    _ => None,
+  }
+}
+
+/**
+ * Parse an angle in degrees, minutes and seconds, such as 45°30'15.5",
+ * 45d30m15s or 45 30 15. Decimal degrees such as 45.5 are also accepted,
+ * and a leading sign applies to the whole angle. Minutes and seconds must be
+ * below 60, and only the last part may have a fraction.
+ */
+pub fn parse_dms(text: &str) -> Result<Degrees, GeometryError> {
+  let text = text.trim();
+  let (negative, text) = match text.strip_prefix('-') {
+    Some(rest) => (true, rest),
+    None => (false, text.strip_prefix('+').unwrap_or(text)),
+  };
+
+  let mut parts: Vec<(usize, &str)> = vec![];
+  let mut rest = text.trim_start();
+  while !rest.is_empty() {
+    let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+    if end == 0 {
+      return Err(GeometryError::UnparsableAngle);
+    }
+    let number = &rest[..end];
+    rest = &rest[end..];
+
+    // An unmarked part takes the unit after the previous one.
+    let next_unit = parts.last().map_or(0, |(unit, _)| unit + 1);
+    let unit = match rest.chars().next().and_then(dms_unit) {
+      Some(unit) => {
+        rest = &rest[rest.chars().next().map_or(0, char::len_utf8)..];
+        unit
+      }
+      None => next_unit,
+    };
+    if unit < next_unit || unit > 2 {
+      return Err(GeometryError::UnparsableAngle);
+    }
+    parts.push((unit, number));
+    rest = rest.trim_start();
+  }
+
+  if parts.is_empty() {
+    return Err(GeometryError::UnparsableAngle);
+  }
+
+  let mut degrees = 0.0;
+  for (i, (unit, number)) in parts.iter().enumerate() {
+    if i + 1 < parts.len() && number.contains('.') {
+      return Err(GeometryError::UnparsableAngle);
+    }
+    let value: f64 = number.parse().map_err(|_| GeometryError::UnparsableAngle)?;
+    if i > 0 && value >= 60.0 {
+      return Err(GeometryError::AngleOutOfRange { angle: value });
+    }
+    degrees += value / 60_f64.powi(*unit as i32);
+  }
+
+  Ok(Degrees(if negative { -degrees } else { degrees }))
+}
+
+/**
+ * Format an angle as degrees, minutes and seconds, such as 45°30'15",
+ * with the seconds rounded to the given number of decimal places.
+ */
+pub fn format_dms(angle: Degrees, decimals: usize) -> String {
+  let scale = 10_f64.powi(decimals as i32);
+  // Round once in whole units of the last decimal so that 59.9999" carries.
+  let total = (angle.0.abs() * 3600.0 * scale).round();
+  let degrees = (total / (3600.0 * scale)).floor();
+  let minutes = ((total - degrees * 3600.0 * scale) / (60.0 * scale)).floor();
+  let seconds = (total - degrees * 3600.0 * scale - minutes * 60.0 * scale) / scale;
+  let sign = if angle.0 < 0.0 && total > 0.0 { "-" } else { "" };
+  let width = if decimals > 0 { decimals + 3 } else { 2 };
+
+  format!("{}{}°{:02}'{:0width$.decimals$}\"", sign, degrees, minutes, seconds, width = width, decimals = decimals)
+}
+
+/**
+ * Parse a compass bearing such as N45°30'E or s 12 30 15 w. The angle must
+ * lie between 0° and 90°.
+ */
+pub fn parse_bearing(text: &str) -> Result<Bearing, GeometryError> {
+  let text = text.trim();
+  let mut chars = text.chars();
+  let from = match chars.next() {
+    Some('N' | 'n') => NorthSouth::North,
+    Some('S' | 's') => NorthSouth::South,
+    _ => return Err(GeometryError::UnparsableAngle),
+  };
+  let towards = match chars.next_back() {
+    Some('E' | 'e') => EastWest::East,
+    Some('W' | 'w') => EastWest::West,
+    _ => return Err(GeometryError::UnparsableAngle),
+  };
+
+  let angle = chars.as_str();
+  if angle.trim_start().starts_with(['-', '+']) {
+    return Err(GeometryError::UnparsableAngle);
+  }
+  let angle = parse_dms(angle)?;
+  if angle.0 > 90.0 {
+    return Err(GeometryError::AngleOutOfRange { angle: angle.0 });
+  }
+
+  Ok(Bearing { from, angle, towards })
+}
+
+/**
+ * Format a compass bearing such as N45°30'00"E, with the seconds rounded
+ * to the given number of decimal places.
+ */
+pub fn format_bearing(bearing: &Bearing, decimals: usize) -> String {
+  let from = match bearing.from {
+    NorthSouth::North => 'N',
+    NorthSouth::South => 'S',
+  };
+  let towards = match bearing.towards {
+    EastWest::East => 'E',
+    EastWest::West => 'W',
+  };
+
+  format!("{}{}{}", from, format_dms(bearing.angle, decimals), towards)
+}
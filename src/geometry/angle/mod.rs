@@ -1 +1,3 @@
-pub mod angle;
\ No newline at end of file
+pub mod angle;
+pub mod types;
+pub mod bearing;
\ No newline at end of file
@@ -0,0 +1,117 @@
+use std::ops::{Add, Mul, Neg, Sub};
+use serde::{Serialize, Deserialize};
+
+/**
+ * An angle measured in degrees. Kept apart from Radians so that the two
+ * units cannot be mixed without an explicit conversion.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/**
+ * An angle measured in radians. Functions taking `impl Into<Radians>` also
+ * accept Degrees, and bare f64 values, which are read as radians.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+/**
+ * A range of polar angles starting at an angle and sweeping by a signed
+ * amount, counter-clockwise when positive. Sweeps of a full turn or more
+ * cover every angle.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AngleRange {
+  pub start: f64,
+  pub sweep: f64,
+}
+
+impl From<Degrees> for Radians {
+  fn from(angle: Degrees) -> Self {
+    Radians(angle.0.to_radians())
+  }
+}
+
+impl From<f64> for Radians {
+  fn from(angle: f64) -> Self {
+    Radians(angle)
+  }
+}
+
+impl From<Radians> for Degrees {
+  fn from(angle: Radians) -> Self {
+    Degrees(angle.0.to_degrees())
+  }
+}
+
+macro_rules! impl_angle_ops {
+  ($unit:ident) => {
+    impl Add for $unit {
+      type Output = $unit;
+
+      fn add(self, other: $unit) -> $unit {
+        $unit(self.0 + other.0)
+      }
+    }
+
+    impl Sub for $unit {
+      type Output = $unit;
+
+      fn sub(self, other: $unit) -> $unit {
+        $unit(self.0 - other.0)
+      }
+    }
+
+    impl Neg for $unit {
+      type Output = $unit;
+
+      fn neg(self) -> $unit {
+        $unit(-self.0)
+      }
+    }
+
+    impl Mul<f64> for $unit {
+      type Output = $unit;
+
+      fn mul(self, scalar: f64) -> $unit {
+        $unit(self.0 * scalar)
+      }
+    }
+  };
+}
+
+impl_angle_ops!(Degrees);
+impl_angle_ops!(Radians);
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, Eq, PartialEq)]
+pub enum NorthSouth {
+  North,
+  South,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, Eq, PartialEq)]
+pub enum EastWest {
+  East,
+  West,
+}
+
+/**
+ * A compass bearing such as N45°30'E: an angle of at most 90° turned from
+ * north or south towards east or west.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Bearing {
+  pub from: NorthSouth,
+  pub angle: Degrees,
+  pub towards: EastWest,
+}
+
+/**
+ * One leg of a traverse: a direction given as an azimuth, clockwise from
+ * north, and the distance travelled along it.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TraverseLeg {
+  pub azimuth: Degrees,
+  pub distance: f64,
+}
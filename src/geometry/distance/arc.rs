@@ -0,0 +1,38 @@
+use crate::geometry::distance::types::ClosestPoint;
+use crate::geometry::point::point::{p2p_angle, p2p_dist};
+use crate::geometry::stroke::arc::{arc_center, arc_contains_angle, arc_param_of_point, arc_radius};
+use crate::geometry::types::stroke_types::ArcBehavior;
+use crate::geometry::types::types::XY;
+
+/**
+ * Point on an arc nearest to the given point. Points whose direction from
+ * the center falls outside the sweep are nearest to one of the endpoints.
+ * Every point of the arc is equally near its center, in which case p1 is used.
+ */
+pub fn closest_point_on_arc<T: ArcBehavior>(point: XY, arc: &T) -> ClosestPoint {
+  let center = arc_center(arc);
+  let radius = arc_radius(arc);
+
+  if point != center {
+    let angle = p2p_angle(center, point);
+    if arc_contains_angle(arc, angle, Some(0.0)) {
+      let closest = XY {
+        x: center.x + radius * angle.cos(),
+        y: center.y + radius * angle.sin(),
+      };
+      return ClosestPoint {
+        point: closest,
+        t: arc_param_of_point(arc, closest).clamp(0.0, 1.0),
+        distance: (p2p_dist(point, center) - radius).abs(),
+      };
+    }
+  }
+
+  let to_p1 = p2p_dist(point, arc.get_p1());
+  let to_p2 = p2p_dist(point, arc.get_p2());
+  if to_p1 <= to_p2 {
+    ClosestPoint { point: arc.get_p1(), t: 0.0, distance: to_p1 }
+  } else {
+    ClosestPoint { point: arc.get_p2(), t: 1.0, distance: to_p2 }
+  }
+}
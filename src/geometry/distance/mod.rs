@@ -0,0 +1,4 @@
+pub mod types;
+pub mod segment;
+pub mod arc;
+pub mod stroke;
\ No newline at end of file
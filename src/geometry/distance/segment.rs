@@ -0,0 +1,19 @@
+use crate::geometry::distance::types::ClosestPoint;
+use crate::geometry::point::point::p2p_dist;
+use crate::geometry::stroke::segment::{segment_param_of_point, segment_point_at};
+use crate::geometry::types::stroke_types::SegmentBehavior;
+use crate::geometry::types::types::XY;
+
+/**
+ * Point on a segment nearest to the given point.
+ */
+pub fn closest_point_on_segment<T: SegmentBehavior>(point: XY, segment: &T) -> ClosestPoint {
+  let t = segment_param_of_point(segment, point).clamp(0.0, 1.0);
+  let closest = segment_point_at(segment, t);
+
+  ClosestPoint {
+    point: closest,
+    t,
+    distance: p2p_dist(point, closest),
+  }
+}
@@ -0,0 +1,107 @@
+use std::f64::consts::PI;
+use crate::geometry::distance::arc::closest_point_on_arc;
+use crate::geometry::distance::segment::closest_point_on_segment;
+use crate::geometry::distance::types::{ClosestPoint, StrokeDistance};
+use crate::geometry::intersection::stroke::stroke_intersection;
+use crate::geometry::intersection::types::Intersection;
+use crate::geometry::point::point::{p2p_angle, p2p_dist};
+use crate::geometry::stroke::arc::{arc_center, arc_contains_angle, arc_radius};
+use crate::geometry::types::stroke_types::{StrokeBehavior, StrokeType};
+use crate::geometry::types::types::XY;
+use crate::utils::utils::Tolerance;
+
+/**
+ * Point on a stroke nearest to the given point.
+ */
+pub fn closest_point_on_stroke<T: StrokeBehavior>(point: XY, stroke: &T) -> ClosestPoint {
+  match stroke.get_type() {
+    StrokeType::Segment => closest_point_on_segment(point, stroke),
+    StrokeType::Arc => closest_point_on_arc(point, stroke),
+  }
+}
+
+/**
+ * Shortest distance from a point to a stroke.
+ */
+pub fn point_stroke_distance<T: StrokeBehavior>(point: XY, stroke: &T) -> f64 {
+  closest_point_on_stroke(point, stroke).distance
+}
+
+/**
+ * Points on a stroke where the distance to another stroke can reach its minimum
+ * when the two do not intersect: the endpoints, and for arcs the points whose
+ * radius is perpendicular to the other segment or aligned with the other center.
+ */
+fn distance_candidates<T: StrokeBehavior, U: StrokeBehavior>(stroke: &T, other: &U) -> Vec<XY> {
+  let mut candidates = vec![stroke.get_p1(), stroke.get_p2()];
+  if let StrokeType::Segment = stroke.get_type() {
+    return candidates;
+  }
+
+  let center = arc_center(stroke);
+  let radius = arc_radius(stroke);
+  let angle = match other.get_type() {
+    StrokeType::Segment => p2p_angle(other.get_p1(), other.get_p2()) + 0.5 * PI,
+    StrokeType::Arc => p2p_angle(center, arc_center(other)),
+  };
+
+  for angle in [angle, angle + PI] {
+    if arc_contains_angle(stroke, angle, Some(0.0)) {
+      candidates.push(XY {
+        x: center.x + radius * angle.cos(),
+        y: center.y + radius * angle.sin(),
+      });
+    }
+  }
+
+  candidates
+}
+
+/**
+ * Shortest distance between two strokes, with the witness point on each.
+ * Strokes that intersect within tolerance are zero apart, and the first
+ * intersection along the first stroke is used as the witness.
+ */
+pub fn stroke_stroke_distance<T: StrokeBehavior, U: StrokeBehavior>(
+  s1: &T,
+  s2: &U,
+  tolerance: impl Into<Tolerance>,
+) -> StrokeDistance {
+  if let Some(intersection) = stroke_intersection(s1, s2, tolerance).first() {
+    let shared = match intersection {
+      Intersection::Point(point) => *point,
+      Intersection::Overlap { start, .. } => *start,
+    };
+    return StrokeDistance {
+      distance: 0.0,
+      point1: shared.point,
+      t1: shared.t1,
+      point2: shared.point,
+      t2: shared.t2,
+    };
+  }
+
+  let from_first = distance_candidates(s1, s2).into_iter().map(|point| {
+    let on_first = closest_point_on_stroke(point, s1);
+    let on_second = closest_point_on_stroke(point, s2);
+    (on_first, on_second)
+  });
+  let from_second = distance_candidates(s2, s1).into_iter().map(|point| {
+    let on_first = closest_point_on_stroke(point, s1);
+    let on_second = closest_point_on_stroke(point, s2);
+    (on_first, on_second)
+  });
+
+  from_first.chain(from_second)
+    .map(|(on_first, on_second)| {
+      StrokeDistance {
+        distance: p2p_dist(on_first.point, on_second.point),
+        point1: on_first.point,
+        t1: on_first.t,
+        point2: on_second.point,
+        t2: on_second.t,
+      }
+    })
+    .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    .expect("every stroke has endpoint candidates")
+}
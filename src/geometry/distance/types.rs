@@ -0,0 +1,25 @@
+use crate::geometry::types::types::XY;
+
+/**
+ * Point on a stroke nearest to some query, along with its normalized
+ * parameter on the stroke and its distance from the query.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClosestPoint {
+  pub point: XY,
+  pub t: f64,
+  pub distance: f64,
+}
+
+/**
+ * Shortest distance between two strokes, with the witness point on each
+ * stroke and its normalized parameter there.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrokeDistance {
+  pub distance: f64,
+  pub point1: XY,
+  pub t1: f64,
+  pub point2: XY,
+  pub t2: f64,
+}
@@ -0,0 +1,32 @@
+use crate::geometry::predicates::predicates::orient2d;
+use crate::geometry::types::types::XY;
+
+/**
+ * Convex hull of a set of points, counter-clockwise from the lowest then
+ * leftmost point. Points on the hull's edges and repeated points are
+ * dropped. Built on the exact orientation predicate, so nearly collinear
+ * input cannot produce a non-convex result.
+ */
+pub fn convex_hull(points: &[XY]) -> Vec<XY> {
+  let mut sorted: Vec<XY> = points.to_vec();
+  sorted.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+  sorted.dedup();
+  if sorted.len() < 3 {
+    return sorted;
+  }
+
+  let mut hull: Vec<XY> = Vec::with_capacity(sorted.len() + 1);
+  // Right chain going up, then left chain coming back down.
+  for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+    let start = hull.len();
+    for p in pass {
+      while hull.len() >= start + 2 && orient2d(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+        hull.pop();
+      }
+      hull.push(p);
+    }
+    hull.pop();
+  }
+
+  hull
+}
@@ -0,0 +1 @@
+pub mod hull;
\ No newline at end of file
@@ -0,0 +1,297 @@
+use std::f64::consts::PI;
+use crate::geometry::angle::angle::ccw_angle_difference;
+use crate::geometry::intersection::segment::{param_within, segment_segment_intersection, snap_param};
+use crate::geometry::intersection::types::{Intersection, IntersectionPoint};
+use crate::geometry::point::point::{p2p_angle, p2p_dist, point_tolerance};
+use crate::geometry::stroke::arc::{
+  arc_center,
+  arc_direction,
+  arc_length,
+  arc_param_of_point,
+  arc_radius,
+  arc_sweep_angle,
+};
+use crate::geometry::stroke::segment::{segment_length, segment_param_of_point};
+use crate::geometry::types::stroke_types::{Arc, ArcBehavior, Segment, SegmentBehavior, Stroke};
+use crate::geometry::types::types::{Orientation, XY};
+use crate::utils::utils::Tolerance;
+
+/**
+ * Replace a computed point with a stored endpoint when it lies within
+ * tolerance of one, so that touching strokes stay exact.
+ */
+fn snap_to_endpoints(point: XY, endpoints: &[XY], tolerance: f64) -> XY {
+  endpoints.iter()
+    .find(|endpoint| p2p_dist(point, **endpoint) < tolerance)
+    .copied()
+    .unwrap_or(point)
+}
+
+fn first_param(intersection: &Intersection) -> f64 {
+  match intersection {
+    Intersection::Point(point) => point.t1,
+    Intersection::Overlap { start, end, .. } => start.t1.min(end.t1),
+  }
+}
+
+/**
+ * Order intersections along the first stroke and drop repeated points.
+ */
+fn sort_intersections(mut intersections: Vec<Intersection>, tolerance: f64) -> Vec<Intersection> {
+  intersections.sort_by(|a, b| first_param(a).total_cmp(&first_param(b)));
+  intersections.dedup_by(|b, a| match (a, b) {
+    (Intersection::Point(a), Intersection::Point(b)) => p2p_dist(a.point, b.point) < tolerance,
+    _ => false,
+  });
+  intersections
+}
+
+/**
+ * Collapse an arc that sweeps no angle to a zero length segment at its start.
+ */
+fn degenerate_arc<T: ArcBehavior>(arc: &T) -> Option<Segment> {
+  if arc_sweep_angle(arc) == 0.0 {
+    Some(Segment { p1: arc.get_p1(), p2: arc.get_p1() })
+  } else {
+    None
+  }
+}
+
+fn swap_params(intersection: Intersection) -> Intersection {
+  let swap = |p: IntersectionPoint| IntersectionPoint { point: p.point, t1: p.t2, t2: p.t1 };
+  match intersection {
+    Intersection::Point(point) => Intersection::Point(swap(point)),
+    Intersection::Overlap { stroke, start, end } => Intersection::Overlap { stroke, start: swap(start), end: swap(end) },
+  }
+}
+
+/**
+ * Intersect a segment with an arc. A segment touching the arc's circle
+ * tangentially produces a single point. Only points within the sweep
+ * of the arc are reported. The relative part of the tolerance is scaled by
+ * the coordinates of both strokes.
+ */
+pub fn segment_arc_intersection<T: SegmentBehavior, U: ArcBehavior>(
+  segment: &T,
+  arc: &U,
+  tolerance: impl Into<Tolerance>,
+) -> Vec<Intersection> {
+  let center = arc_center(arc);
+  let tolerance = point_tolerance(&[segment.get_p1(), segment.get_p2(), arc.get_p1(), arc.get_p2(), center], tolerance);
+  if let Some(point) = degenerate_arc(arc) {
+    return segment_segment_intersection(segment, &point, tolerance).into_iter().collect();
+  }
+
+  let radius = arc_radius(arc);
+  let p1 = segment.get_p1();
+  let p2 = segment.get_p2();
+  let length = segment_length(segment);
+  let endpoints = [p1, p2, arc.get_p1(), arc.get_p2()];
+
+  let candidates: Vec<XY> = if length < tolerance {
+    vec![p1]
+  } else {
+    let (ux, uy) = ((p2.x - p1.x) / length, (p2.y - p1.y) / length);
+    let along = (center.x - p1.x) * ux + (center.y - p1.y) * uy;
+    let foot = XY { x: p1.x + along * ux, y: p1.y + along * uy };
+    let offset = p2p_dist(center, foot);
+
+    if (offset - radius).abs() < tolerance {
+      vec![foot]
+    } else if offset > radius {
+      vec![]
+    } else {
+      let half_chord = (radius * radius - offset * offset).sqrt();
+      vec![
+        XY { x: foot.x - half_chord * ux, y: foot.y - half_chord * uy },
+        XY { x: foot.x + half_chord * ux, y: foot.y + half_chord * uy },
+      ]
+    }
+  };
+
+  let arc_len = arc_length(arc);
+  let mut intersections = vec![];
+  for candidate in candidates {
+    if (p2p_dist(center, candidate) - radius).abs() >= tolerance {
+      continue;
+    }
+
+    let point = snap_to_endpoints(candidate, &endpoints, tolerance);
+    let t1 = segment_param_of_point(segment, point);
+    let t2 = arc_param_of_point(arc, point);
+    if param_within(t1, length, tolerance) && param_within(t2, arc_len, tolerance) {
+      intersections.push(Intersection::Point(IntersectionPoint {
+        point,
+        t1: snap_param(t1, length, tolerance),
+        t2: snap_param(t2, arc_len, tolerance),
+      }));
+    }
+  }
+
+  sort_intersections(intersections, tolerance)
+}
+
+/**
+ * Intersect an arc with a segment, with parameters reported arc first.
+ */
+pub fn arc_segment_intersection<T: ArcBehavior, U: SegmentBehavior>(
+  arc: &T,
+  segment: &U,
+  tolerance: impl Into<Tolerance>,
+) -> Vec<Intersection> {
+  let tolerance = point_tolerance(&[arc.get_p1(), arc.get_p2(), arc_center(arc), segment.get_p1(), segment.get_p2()], tolerance);
+  let intersections = segment_arc_intersection(segment, arc, tolerance)
+    .into_iter()
+    .map(swap_params)
+    .collect();
+  sort_intersections(intersections, tolerance)
+}
+
+/**
+ * Counter-clockwise start angle and angular width of an arc.
+ */
+fn ccw_interval<T: ArcBehavior>(arc: &T) -> (f64, f64) {
+  let center = arc_center(arc);
+  let start = match arc_direction(arc) {
+    Orientation::CounterClockwise => arc.get_p1(),
+    Orientation::Clockwise => arc.get_p2(),
+  };
+  (p2p_angle(center, start), arc_sweep_angle(arc))
+}
+
+/**
+ * Intersect two arcs lying on the same circle. Each shared piece of the
+ * circle is reported as an overlap, or as a point when the arcs only touch.
+ */
+fn cocircular_intersection<T: ArcBehavior, U: ArcBehavior>(
+  a1: &T,
+  a2: &U,
+  tolerance: f64,
+) -> Vec<Intersection> {
+  let center = arc_center(a1);
+  let radius = arc_radius(a1);
+  let endpoints = [a1.get_p1(), a1.get_p2(), a2.get_p1(), a2.get_p2()];
+  let length1 = arc_length(a1);
+  let length2 = arc_length(a2);
+  let direction = arc_direction(a1);
+
+  let (start1, width1) = ccw_interval(a1);
+  let (start2, width2) = ccw_interval(a2);
+  let offset = ccw_angle_difference(start1, start2);
+
+  let at = |angle: f64| {
+    let angle = start1 + angle;
+    let point = XY { x: center.x + radius * angle.cos(), y: center.y + radius * angle.sin() };
+    let point = snap_to_endpoints(point, &endpoints, tolerance);
+    IntersectionPoint {
+      point,
+      t1: snap_param(arc_param_of_point(a1, point), length1, tolerance),
+      t2: snap_param(arc_param_of_point(a2, point), length2, tolerance),
+    }
+  };
+
+  let mut intersections = vec![];
+  // The second arc may wrap past the end of the first, giving up to two pieces.
+  for shift in [offset, offset - 2.0 * PI] {
+    let lo = shift.max(0.0);
+    let hi = (shift + width2).min(width1);
+    if (lo - hi) * radius >= tolerance {
+      continue;
+    }
+
+    let hi = hi.max(lo);
+    if (hi - lo) * radius < tolerance {
+      intersections.push(Intersection::Point(at(lo)));
+      continue;
+    }
+
+    let (start, end) = match direction {
+      Orientation::CounterClockwise => (at(lo), at(hi)),
+      Orientation::Clockwise => (at(hi), at(lo)),
+    };
+    intersections.push(Intersection::Overlap {
+      stroke: Stroke::Arc(Arc {
+        p1: start.point,
+        p2: end.point,
+        center,
+        major: Some(hi - lo > PI),
+        direction: Some(direction),
+      }),
+      start,
+      end,
+    });
+  }
+
+  sort_intersections(intersections, tolerance)
+}
+
+/**
+ * Intersect two arcs. Circles touching tangentially produce a single point,
+ * and arcs on the same circle produce their overlapping pieces. Only points
+ * within the sweep of both arcs are reported. The relative part of the
+ * tolerance is scaled by the coordinates of both arcs.
+ */
+pub fn arc_arc_intersection<T: ArcBehavior, U: ArcBehavior>(
+  a1: &T,
+  a2: &U,
+  tolerance: impl Into<Tolerance>,
+) -> Vec<Intersection> {
+  let c1 = arc_center(a1);
+  let c2 = arc_center(a2);
+  let tolerance = point_tolerance(&[a1.get_p1(), a1.get_p2(), c1, a2.get_p1(), a2.get_p2(), c2], tolerance);
+  if let Some(point) = degenerate_arc(a1) {
+    return segment_arc_intersection(&point, a2, tolerance);
+  }
+  if let Some(point) = degenerate_arc(a2) {
+    return arc_segment_intersection(a1, &point, tolerance);
+  }
+
+  let r1 = arc_radius(a1);
+  let r2 = arc_radius(a2);
+  let distance = p2p_dist(c1, c2);
+
+  if distance < tolerance {
+    if (r1 - r2).abs() < tolerance {
+      return cocircular_intersection(a1, a2, tolerance);
+    }
+    // Concentric circles of different sizes never meet.
+    return vec![];
+  }
+  if distance > r1 + r2 + tolerance || distance < (r1 - r2).abs() - tolerance {
+    return vec![];
+  }
+
+  let (ux, uy) = ((c2.x - c1.x) / distance, (c2.y - c1.y) / distance);
+  let along = (distance * distance + r1 * r1 - r2 * r2) / (2.0 * distance);
+  let base = XY { x: c1.x + along * ux, y: c1.y + along * uy };
+  let tangent = (distance - (r1 + r2)).abs() < tolerance || (distance - (r1 - r2).abs()).abs() < tolerance;
+
+  let candidates = if tangent {
+    vec![base]
+  } else {
+    let half_chord = (r1 * r1 - along * along).max(0.0).sqrt();
+    vec![
+      XY { x: base.x - half_chord * uy, y: base.y + half_chord * ux },
+      XY { x: base.x + half_chord * uy, y: base.y - half_chord * ux },
+    ]
+  };
+
+  let endpoints = [a1.get_p1(), a1.get_p2(), a2.get_p1(), a2.get_p2()];
+  let length1 = arc_length(a1);
+  let length2 = arc_length(a2);
+  let mut intersections = vec![];
+  for candidate in candidates {
+    let point = snap_to_endpoints(candidate, &endpoints, tolerance);
+    let t1 = arc_param_of_point(a1, point);
+    let t2 = arc_param_of_point(a2, point);
+    if param_within(t1, length1, tolerance) && param_within(t2, length2, tolerance) {
+      intersections.push(Intersection::Point(IntersectionPoint {
+        point,
+        t1: snap_param(t1, length1, tolerance),
+        t2: snap_param(t2, length2, tolerance),
+      }));
+    }
+  }
+
+  sort_intersections(intersections, tolerance)
+}
@@ -0,0 +1,174 @@
+use crate::geometry::intersection::types::{Intersection, IntersectionPoint};
+use crate::geometry::point::point::{p2p_dist, point_tolerance};
+use crate::geometry::predicates::predicates::orient2d;
+use crate::geometry::stroke::segment::{segment_length, segment_param_of_point, segment_point_at};
+use crate::geometry::types::stroke_types::{Segment, SegmentBehavior, Stroke};
+use crate::geometry::types::types::XY;
+use crate::utils::utils::Tolerance;
+
+/**
+ * Determine if two orientations put the ends of a segment on opposite sides
+ * of a line, allowing one end to lie on it.
+ */
+fn straddles(o1: f64, o2: f64) -> bool {
+  (o1 <= 0.0 && o2 >= 0.0 || o1 >= 0.0 && o2 <= 0.0) && o1 != o2
+}
+
+/**
+ * Snap a normalized parameter onto the ends of a stroke of the given length
+ * when it lies within tolerance of them, otherwise clamp it to [0, 1].
+ */
+pub(crate) fn snap_param(t: f64, length: f64, tolerance: f64) -> f64 {
+  if (t * length).abs() < tolerance {
+    0.0
+  } else if ((1.0 - t) * length).abs() < tolerance {
+    1.0
+  } else {
+    t.clamp(0.0, 1.0)
+  }
+}
+
+/**
+ * Determine if a normalized parameter lies on a stroke of the given length,
+ * allowing it to run past either end by up to the tolerance.
+ */
+pub(crate) fn param_within(t: f64, length: f64, tolerance: f64) -> bool {
+  t * length > -tolerance && (t - 1.0) * length < tolerance
+}
+
+/**
+ * Parameter of a point on a segment, if the point lies on it within tolerance.
+ */
+fn point_on_segment<T: SegmentBehavior>(point: XY, segment: &T, tolerance: f64) -> Option<f64> {
+  let t = segment_param_of_point(segment, point).clamp(0.0, 1.0);
+  if p2p_dist(point, segment_point_at(segment, t)) < tolerance {
+    Some(snap_param(t, segment_length(segment), tolerance))
+  } else {
+    None
+  }
+}
+
+/**
+ * Location of a shared point, preferring the stored endpoints of either
+ * segment over a computed position so that touching strokes stay exact.
+ */
+fn shared_point<T: SegmentBehavior, U: SegmentBehavior>(s1: &T, t1: f64, s2: &U, t2: f64) -> XY {
+  if t1 == 0.0 {
+    s1.get_p1()
+  } else if t1 == 1.0 {
+    s1.get_p2()
+  } else if t2 == 0.0 {
+    s2.get_p1()
+  } else if t2 == 1.0 {
+    s2.get_p2()
+  } else {
+    segment_point_at(s1, t1)
+  }
+}
+
+fn intersection_point<T: SegmentBehavior, U: SegmentBehavior>(s1: &T, t1: f64, s2: &U, t2: f64) -> IntersectionPoint {
+  IntersectionPoint { point: shared_point(s1, t1, s2, t2), t1, t2 }
+}
+
+/**
+ * Intersect two segments lying on the same line.
+ */
+fn collinear_intersection<T: SegmentBehavior, U: SegmentBehavior>(
+  s1: &T,
+  s2: &U,
+  tolerance: f64,
+) -> Option<Intersection> {
+  let length1 = segment_length(s1);
+  let length2 = segment_length(s2);
+  let a = segment_param_of_point(s1, s2.get_p1());
+  let b = segment_param_of_point(s1, s2.get_p2());
+
+  let lo = a.min(b).max(0.0);
+  let hi = a.max(b).min(1.0);
+  if (lo - hi) * length1 >= tolerance {
+    return None;
+  }
+
+  let lo = snap_param(lo, length1, tolerance);
+  let hi = snap_param(hi.max(lo), length1, tolerance);
+  let lo_on_s2 = snap_param(segment_param_of_point(s2, segment_point_at(s1, lo)), length2, tolerance);
+  let hi_on_s2 = snap_param(segment_param_of_point(s2, segment_point_at(s1, hi)), length2, tolerance);
+
+  let start = intersection_point(s1, lo, s2, lo_on_s2);
+  if (hi - lo) * length1 < tolerance {
+    return Some(Intersection::Point(start));
+  }
+
+  let end = intersection_point(s1, hi, s2, hi_on_s2);
+  Some(Intersection::Overlap {
+    stroke: Stroke::Segment(Segment { p1: start.point, p2: end.point }),
+    start,
+    end,
+  })
+}
+
+/**
+ * Intersect two segments. Segments that cross or touch produce a single point,
+ * and collinear segments that share more than a point produce the overlapping
+ * sub-segment. Endpoints within tolerance of the other segment count as touching.
+ * The relative part of the tolerance is scaled by the segments' coordinates.
+ */
+pub fn segment_segment_intersection<T: SegmentBehavior, U: SegmentBehavior>(
+  s1: &T,
+  s2: &U,
+  tolerance: impl Into<Tolerance>,
+) -> Option<Intersection> {
+  let p1 = s1.get_p1();
+  let p2 = s1.get_p2();
+  let q1 = s2.get_p1();
+  let q2 = s2.get_p2();
+  let tolerance = point_tolerance(&[p1, p2, q1, q2], tolerance);
+  let length1 = segment_length(s1);
+  let length2 = segment_length(s2);
+
+  // Degenerate segments behave like points.
+  if length1 < tolerance {
+    return point_on_segment(p1, s2, tolerance)
+      .map(|t2| Intersection::Point(IntersectionPoint { point: p1, t1: 0.0, t2 }));
+  }
+  if length2 < tolerance {
+    return point_on_segment(q1, s1, tolerance)
+      .map(|t1| Intersection::Point(IntersectionPoint { point: q1, t1, t2: 0.0 }));
+  }
+
+  // Twice the signed areas that each end of one segment makes with the
+  // other, which have exact signs. Divided by a length they give distances.
+  let o1 = orient2d(p1, p2, q1);
+  let o2 = orient2d(p1, p2, q2);
+  let o3 = orient2d(q1, q2, p1);
+  let o4 = orient2d(q1, q2, p2);
+
+  let exactly_collinear = o1 == 0.0 && o2 == 0.0;
+  if exactly_collinear || (o1.abs() / length1 < tolerance && o2.abs() / length1 < tolerance) {
+    return collinear_intersection(s1, s2, tolerance);
+  }
+
+  // The areas change linearly along each segment, vanishing where it meets
+  // the line through the other.
+  if straddles(o1, o2) && straddles(o3, o4) {
+    let t1 = snap_param(o3 / (o3 - o4), length1, tolerance);
+    let t2 = snap_param(o1 / (o1 - o2), length2, tolerance);
+    return Some(Intersection::Point(intersection_point(s1, t1, s2, t2)));
+  }
+
+  // Nearly parallel segments can still touch end to side within tolerance.
+  if let Some(t2) = point_on_segment(p1, s2, tolerance) {
+    return Some(Intersection::Point(intersection_point(s1, 0.0, s2, t2)));
+  }
+  if let Some(t2) = point_on_segment(p2, s2, tolerance) {
+    return Some(Intersection::Point(intersection_point(s1, 1.0, s2, t2)));
+  }
+  if let Some(t1) = point_on_segment(q1, s1, tolerance) {
+    return Some(Intersection::Point(intersection_point(s1, t1, s2, 0.0)));
+  }
+  if let Some(t1) = point_on_segment(q2, s1, tolerance) {
+    return Some(Intersection::Point(intersection_point(s1, t1, s2, 1.0)));
+  }
+
+  None
+}
@@ -0,0 +1,21 @@
+use crate::geometry::intersection::arc::{arc_arc_intersection, arc_segment_intersection, segment_arc_intersection};
+use crate::geometry::intersection::segment::segment_segment_intersection;
+use crate::geometry::intersection::types::Intersection;
+use crate::geometry::types::stroke_types::{StrokeBehavior, StrokeType};
+use crate::utils::utils::Tolerance;
+
+/**
+ * Intersect any two strokes, ordered by their position along the first stroke.
+ */
+pub fn stroke_intersection<T: StrokeBehavior, U: StrokeBehavior>(
+  s1: &T,
+  s2: &U,
+  tolerance: impl Into<Tolerance>,
+) -> Vec<Intersection> {
+  match (s1.get_type(), s2.get_type()) {
+    (StrokeType::Segment, StrokeType::Segment) => segment_segment_intersection(s1, s2, tolerance).into_iter().collect(),
+    (StrokeType::Segment, StrokeType::Arc) => segment_arc_intersection(s1, s2, tolerance),
+    (StrokeType::Arc, StrokeType::Segment) => arc_segment_intersection(s1, s2, tolerance),
+    (StrokeType::Arc, StrokeType::Arc) => arc_arc_intersection(s1, s2, tolerance),
+  }
+}
@@ -0,0 +1,28 @@
+use crate::geometry::types::stroke_types::Stroke;
+use crate::geometry::types::types::XY;
+
+/**
+ * A point shared by two strokes, along with the normalized parameter
+ * of that point on the first (t1) and second (t2) stroke.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntersectionPoint {
+  pub point: XY,
+  pub t1: f64,
+  pub t2: f64,
+}
+
+/**
+ * Result of intersecting two strokes. Strokes either cross or touch at a
+ * single point, or they run along each other, in which case the shared
+ * piece is reported as a stroke running in the direction of the first input.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Intersection {
+  Point(IntersectionPoint),
+  Overlap {
+    stroke: Stroke,
+    start: IntersectionPoint,
+    end: IntersectionPoint,
+  },
+}
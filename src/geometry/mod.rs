@@ -2,4 +2,11 @@ pub mod point;
 pub mod types;
 pub mod angle;
 pub mod stroke;
-pub mod path;
\ No newline at end of file
+pub mod path;
+pub mod intersection;
+pub mod distance;
+pub mod transform;
+pub mod vector;
+pub mod space;
+pub mod predicates;
+pub mod hull;
\ No newline at end of file
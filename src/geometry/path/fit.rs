@@ -0,0 +1,115 @@
+use crate::geometry::distance::arc::closest_point_on_arc;
+use crate::geometry::distance::segment::closest_point_on_segment;
+use crate::geometry::intersection::segment::param_within;
+use crate::geometry::point::point::{p2p_dist, point_tolerance, points_equal};
+use crate::geometry::stroke::arc::{arc_from_three_points, arc_length, arc_param_of_point, arc_radius};
+use crate::geometry::types::stroke_types::{Arc, Segment, SegmentBehavior, Stroke};
+use crate::geometry::types::types::XY;
+use crate::utils::utils::Tolerance;
+
+/**
+ * Determine if every point lies within tolerance of the segment joining the
+ * first and last point.
+ */
+fn fit_segment(points: &[XY], tolerance: f64) -> Option<Segment> {
+  let segment = Segment { p1: points[0], p2: points[points.len() - 1] };
+  if points.iter().all(|p| closest_point_on_segment(*p, &segment).distance <= tolerance) {
+    Some(segment)
+  } else {
+    None
+  }
+}
+
+/**
+ * Fit an arc through the first, middle and last point, keeping it only if the
+ * points, and the straight runs between them, stay within tolerance of the arc
+ * and are visited in order along it.
+ */
+fn fit_arc(points: &[XY], tolerance: f64) -> Option<Arc> {
+  if points.len() < 3 {
+    return None;
+  }
+
+  let arc = arc_from_three_points(points[0], points[points.len() / 2], points[points.len() - 1]).ok()?;
+  let radius = arc_radius(&arc);
+  let length = arc_length(&arc);
+
+  let mut previous = 0.0;
+  for (i, p) in points.iter().enumerate() {
+    if (p2p_dist(arc.center, *p) - radius).abs() > tolerance {
+      return None;
+    }
+
+    let t = arc_param_of_point(&arc, *p);
+    if !param_within(t, length, tolerance) || (t - previous) * length < -tolerance {
+      return None;
+    }
+    previous = f64::max(previous, t);
+
+    if let Some(next) = points.get(i + 1) {
+      let mid = XY { x: 0.5 * (p.x + next.x), y: 0.5 * (p.y + next.y) };
+      if closest_point_on_arc(mid, &arc).distance > tolerance {
+        return None;
+      }
+    }
+  }
+
+  Some(arc)
+}
+
+/**
+ * Replace a dense polyline with a compact mix of segments and arcs that stay
+ * within tolerance of it. Starting from each vertex, the stroke that covers
+ * the most following vertices is kept, preferring a segment over an arc.
+ * The relative part of the tolerance is scaled by the polyline's coordinates.
+ */
+pub fn fit_polyline(points: &[XY], tolerance: impl Into<Tolerance>) -> Vec<Stroke> {
+  let tolerance = point_tolerance(points, tolerance);
+  let mut points = points.to_vec();
+  points.dedup_by(|b, a| points_equal(*a, *b, Some(tolerance)));
+
+  let mut strokes = Vec::new();
+  let mut start = 0;
+  while start + 1 < points.len() {
+    let mut end = start + 1;
+    let mut best = Stroke::Segment(Segment { p1: points[start], p2: points[end] });
+
+    for candidate in start + 2..points.len() {
+      let span = &points[start..=candidate];
+      if let Some(segment) = fit_segment(span, tolerance) {
+        best = Stroke::Segment(segment);
+      } else if let Some(arc) = fit_arc(span, tolerance) {
+        best = Stroke::Arc(arc);
+      } else {
+        break;
+      }
+      end = candidate;
+    }
+
+    strokes.push(best);
+    start = end;
+  }
+
+  strokes
+}
+
+/**
+ * Replace a chain of segments with a compact mix of segments and arcs, see
+ * `fit_polyline`. Segments that do not join end to start begin a new run,
+ * and each run is fitted separately.
+ */
+pub fn fit_segments<T: SegmentBehavior>(segments: &[T], tolerance: impl Into<Tolerance>) -> Vec<Stroke> {
+  let tolerance = tolerance.into();
+  let mut runs: Vec<Vec<XY>> = Vec::new();
+
+  for segment in segments {
+    match runs.last_mut() {
+      Some(run) if points_equal(*run.last().unwrap(), segment.get_p1(), tolerance) => {
+        run.push(segment.get_p2());
+      },
+      _ => runs.push(vec![segment.get_p1(), segment.get_p2()]),
+    }
+  }
+
+  runs.iter().flat_map(|run| fit_polyline(run, tolerance)).collect()
+}
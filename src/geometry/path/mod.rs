@@ -1 +1,3 @@
-pub mod path;
\ No newline at end of file
+pub mod path;
+pub mod fit;
+pub mod types;
\ No newline at end of file
@@ -1,18 +1,415 @@
-use crate::geometry::types::stroke_types::StrokeBehavior;
+use crate::geometry::types::stroke_types::{AnnotatedStroke, Segment, StrokeBehavior, StrokeType};
+use crate::geometry::stroke::arc::{arc_center, arc_direction, arc_radius, arc_signed_sweep_angle, arc_sweep_angle};
+use crate::geometry::types::types::{BoundingBox, Orientation, Vector2D, XY};
 use std::hash::Hash;
-use crate::geometry::stroke::stroke::reverse_stroke;
-use crate::geometry::point::point::{bounding_box_from_points, points_equal};
+use crate::geometry::stroke::arc::TessellateOpts;
+use crate::geometry::stroke::stroke::{
+  flatten_annotated_stroke,
+  flatten_stroke,
+  reverse_stroke,
+  stroke_bounding_box,
+  stroke_length,
+  stroke_param_at_distance,
+  stroke_point_at,
+  stroke_tangent_at,
+  tessellate_stroke,
+};
+use crate::geometry::distance::stroke::closest_point_on_stroke;
+use crate::geometry::path::types::{Containment, FillRule, Path, PathClosestPoint, PathPosition};
+use crate::geometry::point::point::{p2p_dist, point_tolerance, points_equal};
+use crate::geometry::predicates::predicates::orient2d;
+use crate::geometry::types::error::GeometryError;
 use crate::quadtree::quadtree::QuadtreeProps;
 use crate::quadtree::{quadtree::Quadtree, quadtree_point::QuadtreePoint};
+use crate::utils::utils::Tolerance;
 
 pub struct UnscramblePathOpts<T: StrokeBehavior + Hash + Eq> {
-    pub tolerance: Option<f64>,
+    /**
+     * How close stroke ends must be to join, 0.001 when not given. The
+     * relative part is scaled by the stroke ends. Was `Option<f64>`, wrap
+     * absolute values with `Tolerance::absolute`.
+     */
+    pub tolerance: Option<Tolerance>,
     pub reverse: Option<fn(&T) -> T>,
 }
 
+/**
+ * Tight bounding box of a collection of strokes.
+ */
+pub fn path_bounding_box<T: StrokeBehavior>(strokes: &[T]) -> BoundingBox {
+  strokes.iter().map(stroke_bounding_box).fold(
+    BoundingBox {
+      x_min: f64::INFINITY,
+      x_max: f64::NEG_INFINITY,
+      y_min: f64::INFINITY,
+      y_max: f64::NEG_INFINITY,
+    },
+    |acc, bb| BoundingBox {
+      x_min: acc.x_min.min(bb.x_min),
+      x_max: acc.x_max.max(bb.x_max),
+      y_min: acc.y_min.min(bb.y_min),
+      y_max: acc.y_max.max(bb.y_max),
+    },
+  )
+}
+
+/**
+ * Polylines that follow a sequence of strokes. Consecutive strokes that join
+ * end to start share a vertex and continue the same polyline, a gap starts a new one.
+ */
+pub fn tessellate_path<T: StrokeBehavior>(strokes: &[T], opts: Option<TessellateOpts>) -> Vec<Vec<XY>> {
+  let mut polylines: Vec<Vec<XY>> = Vec::new();
+
+  for stroke in strokes {
+    let points = tessellate_stroke(stroke, opts);
+    match polylines.last_mut() {
+      Some(polyline) if points_equal(*polyline.last().unwrap(), points[0], None) => {
+        polyline.extend(points.into_iter().skip(1));
+      },
+      _ => polylines.push(points),
+    }
+  }
+
+  polylines
+}
+
+/**
+ * Replace every stroke of a path with straight segments, in order.
+ */
+pub fn flatten_path<T: StrokeBehavior>(strokes: &[T], opts: Option<TessellateOpts>) -> Vec<Segment> {
+  strokes.iter().flat_map(|stroke| flatten_stroke(stroke, opts)).collect()
+}
+
+/**
+ * Replace every stroke of an annotated path with straight segments, in
+ * order, each carrying the data of the stroke it came from.
+ */
+pub fn flatten_annotated_path<U: Clone>(
+  strokes: &[AnnotatedStroke<U>],
+  opts: Option<TessellateOpts>,
+) -> Vec<AnnotatedStroke<U>> {
+  strokes.iter().flat_map(|stroke| flatten_annotated_stroke(stroke, opts)).collect()
+}
+
+/**
+ * Total length of the strokes of a path.
+ */
+pub fn path_length<T: StrokeBehavior>(path: &Path<T>) -> f64 {
+  path.iter().map(stroke_length).sum()
+}
+
+/**
+ * Position at a distance along a path. Distances wrap around closed paths
+ * and are clamped to the ends of open ones. A distance landing exactly on a
+ * joint is placed at the start of the following stroke. Empty paths have
+ * no positions.
+ */
+pub fn path_position_at_distance<T: StrokeBehavior>(path: &Path<T>, distance: f64) -> Option<PathPosition> {
+  let last = path.len().checked_sub(1)?;
+  let length = path_length(path);
+  let along = if path.is_closed() && length > 0.0 {
+    distance.rem_euclid(length)
+  } else {
+    distance.clamp(0.0, length)
+  };
+
+  let mut start = 0.0;
+  for (index, stroke) in path.iter().enumerate() {
+    let stroke_len = stroke_length(stroke);
+    if along < start + stroke_len || index == last {
+      return Some(PathPosition { index, t: stroke_param_at_distance(stroke, along - start), along });
+    }
+    start += stroke_len;
+  }
+
+  None
+}
+
+/**
+ * Point at a distance along a path, see `path_position_at_distance`.
+ */
+pub fn path_point_at_distance<T: StrokeBehavior>(path: &Path<T>, distance: f64) -> Option<XY> {
+  path_position_at_distance(path, distance)
+    .map(|position| stroke_point_at(&path.strokes()[position.index], position.t))
+}
+
+/**
+ * Unit tangent at a distance along a path, see `path_position_at_distance`.
+ * At a corner it follows the stroke leaving the corner.
+ */
+pub fn path_tangent_at_distance<T: StrokeBehavior>(path: &Path<T>, distance: f64) -> Option<Vector2D> {
+  path_position_at_distance(path, distance)
+    .map(|position| stroke_tangent_at(&path.strokes()[position.index], position.t))
+}
+
+/**
+ * Point on a path nearest to the given point. When several strokes are
+ * equally near, the earliest along the path is used.
+ */
+pub fn path_closest_point<T: StrokeBehavior>(path: &Path<T>, point: XY) -> Option<PathClosestPoint> {
+  let mut start = 0.0;
+  let mut closest: Option<PathClosestPoint> = None;
+
+  for (index, stroke) in path.iter().enumerate() {
+    let candidate = closest_point_on_stroke(point, stroke);
+    let stroke_len = stroke_length(stroke);
+    let nearer = match &closest {
+      Some(c) => candidate.distance < c.distance,
+      None => true,
+    };
+    if nearer {
+      closest = Some(PathClosestPoint {
+        point: candidate.point,
+        position: PathPosition { index, t: candidate.t, along: start + candidate.t * stroke_len },
+        distance: candidate.distance,
+      });
+    }
+    start += stroke_len;
+  }
+
+  closest
+}
+
+/**
+ * Distance along a path to the point on it nearest to the given point.
+ */
+pub fn path_distance_of_point<T: StrokeBehavior>(path: &Path<T>, point: XY) -> Option<f64> {
+  path_closest_point(path, point).map(|closest| closest.position.along)
+}
+
+/**
+ * Signed area enclosed by a closed path, positive when it runs
+ * counter-clockwise. Each stroke adds the shoelace term of its chord, and
+ * each arc also adds the circular segment between its chord and its curve,
+ * so arcs are measured exactly rather than flattened.
+ */
+pub fn path_signed_area<T: StrokeBehavior>(path: &Path<T>) -> Result<f64, GeometryError> {
+  if !path.is_closed() {
+    return Err(GeometryError::OpenPath);
+  }
+
+  // Measuring from the first vertex keeps large coordinates from cancelling.
+  let origin = match path.strokes().first() {
+    Some(stroke) => stroke.get_p1(),
+    None => return Ok(0.0),
+  };
+
+  let area = path.iter().map(|stroke| {
+    let p1 = stroke.get_p1();
+    let p2 = stroke.get_p2();
+    let chord = ((p1.x - origin.x) * (p2.y - origin.y) - (p2.x - origin.x) * (p1.y - origin.y)) / 2.0;
+
+    match stroke.get_type() {
+      StrokeType::Segment => chord,
+      StrokeType::Arc => {
+        let radius = arc_radius(stroke);
+        let sweep = arc_signed_sweep_angle(stroke);
+        chord + radius * radius * (sweep - sweep.sin()) / 2.0
+      }
+    }
+  }).sum();
+
+  Ok(area)
+}
+
+/**
+ * Unsigned area enclosed by a closed path.
+ */
+pub fn path_area<T: StrokeBehavior>(path: &Path<T>) -> Result<f64, GeometryError> {
+  path_signed_area(path).map(f64::abs)
+}
+
+/**
+ * Direction a closed path runs around the area it encloses, or None when it
+ * encloses no area.
+ */
+pub fn path_orientation<T: StrokeBehavior>(path: &Path<T>) -> Result<Option<Orientation>, GeometryError> {
+  let area = path_signed_area(path)?;
+  Ok(if area > 0.0 {
+    Some(Orientation::CounterClockwise)
+  } else if area < 0.0 {
+    Some(Orientation::Clockwise)
+  } else {
+    None
+  })
+}
+
+/**
+ * Side of the line from p1 to p2 that a point falls on: positive on the left,
+ * negative on the right. A point on the line is nudged a vanishing step in +x
+ * and then a smaller one in +y, matching the half-open rule of `chord_crossing`.
+ */
+fn chord_side(p1: XY, p2: XY, point: XY) -> f64 {
+  let side = orient2d(p1, p2, point);
+  if side != 0.0 {
+    side
+  } else if p1.y != p2.y {
+    p1.y - p2.y
+  } else {
+    p2.x - p1.x
+  }
+}
+
+/**
+ * Signed crossing of the chord from p1 to p2 with the ray running from the
+ * point towards +x: 1 when it crosses upwards, -1 downwards and 0 otherwise.
+ * Each chord covers the heights from its lower end up to but excluding its
+ * upper end, so a ray through a shared vertex is counted once.
+ */
+fn chord_crossing(p1: XY, p2: XY, point: XY) -> i32 {
+  if p1.y <= point.y && point.y < p2.y && chord_side(p1, p2, point) > 0.0 {
+    1
+  } else if p2.y <= point.y && point.y < p1.y && chord_side(p1, p2, point) < 0.0 {
+    -1
+  } else {
+    0
+  }
+}
+
+/**
+ * Winding of an arc and its chord traversed back, around a point: the
+ * region between them is wound once in the direction the arc turns. It lies
+ * inside the circle and on the opposite side of the chord from the direction
+ * the arc turns.
+ */
+fn arc_segment_winding<T: StrokeBehavior>(arc: &T, point: XY) -> i32 {
+  if arc_sweep_angle(arc) == 0.0 || p2p_dist(arc_center(arc), point) >= arc_radius(arc) {
+    return 0;
+  }
+
+  let side = chord_side(arc.get_p1(), arc.get_p2(), point);
+  match arc_direction(arc) {
+    Orientation::CounterClockwise if side < 0.0 => 1,
+    Orientation::Clockwise if side > 0.0 => -1,
+    _ => 0,
+  }
+}
+
+/**
+ * Number of times a closed path winds counter-clockwise around a point,
+ * negative when it winds clockwise. Crossings of the chord of each stroke
+ * are counted with exact orientation tests, and each arc adds the winding
+ * of the region between its curve and its chord, so arcs are never
+ * flattened. Points on the path get the winding of points just beside them.
+ */
+pub fn path_winding_number<T: StrokeBehavior>(path: &Path<T>, point: XY) -> Result<i32, GeometryError> {
+  if !path.is_closed() {
+    return Err(GeometryError::OpenPath);
+  }
+
+  let winding = path.iter().map(|stroke| {
+    let crossing = chord_crossing(stroke.get_p1(), stroke.get_p2(), point);
+    match stroke.get_type() {
+      StrokeType::Segment => crossing,
+      StrokeType::Arc => crossing + arc_segment_winding(stroke, point),
+    }
+  }).sum();
+
+  Ok(winding)
+}
+
+/**
+ * Determine whether a point is inside, outside or on a closed path under a
+ * fill rule. Points within the tolerance of the path are on its boundary,
+ * with the relative part of the tolerance scaled by the path's vertices.
+ */
+pub fn path_contains_point<T: StrokeBehavior>(
+  path: &Path<T>,
+  point: XY,
+  fill_rule: FillRule,
+  tolerance: impl Into<Tolerance>
+) -> Result<Containment, GeometryError> {
+  let winding = path_winding_number(path, point)?;
+
+  let tolerance = point_tolerance(&path.vertices().collect::<Vec<XY>>(), tolerance);
+  let on_boundary = path_closest_point(path, point)
+    .is_some_and(|closest| closest.distance <= tolerance);
+  if on_boundary {
+    return Ok(Containment::Boundary);
+  }
+
+  let inside = match fill_rule {
+    FillRule::EvenOdd => winding % 2 != 0,
+    FillRule::NonZero => winding != 0,
+  };
+  Ok(if inside { Containment::Inside } else { Containment::Outside })
+}
+
+/**
+ * Absolute tolerance for joining strokes, with the relative part scaled by
+ * the stroke ends.
+ */
+pub(crate) fn path_tolerance<T: StrokeBehavior>(strokes: &[T], tolerance: impl Into<Tolerance>) -> f64 {
+  let ends: Vec<XY> = strokes.iter().flat_map(|stroke| [stroke.get_p1(), stroke.get_p2()]).collect();
+  point_tolerance(&ends, tolerance)
+}
+
+/**
+ * Check that each stroke starts where the previous one ends, within tolerance.
+ * The relative part of the tolerance is scaled by the stroke ends, so every
+ * joint is held to the same absolute gap.
+ */
+pub fn path_continuity<T: StrokeBehavior>(strokes: &[T], tolerance: impl Into<Tolerance>) -> Result<(), GeometryError> {
+  let tolerance = path_tolerance(strokes, tolerance);
+  for (index, pair) in strokes.windows(2).enumerate() {
+    let (end, start) = (pair[0].get_p2(), pair[1].get_p1());
+    if !points_equal(end, start, tolerance) {
+      return Err(GeometryError::DiscontinuousPath { index: index + 1, gap: p2p_dist(end, start) });
+    }
+  }
+  Ok(())
+}
+
+/**
+ * Absolute tolerance used to join strokes, see `UnscramblePathOpts`.
+ */
+fn unscramble_tolerance<T: StrokeBehavior + Hash + Eq>(strokes: &[T], opts: &Option<UnscramblePathOpts<T>>) -> f64 {
+  let tolerance = match opts {
+    Some(opt) => opt.tolerance.unwrap_or(Tolerance::absolute(0.001)),
+    None => Tolerance::absolute(0.001)
+  };
+  path_tolerance(strokes, tolerance)
+}
+
+/**
+ * Reverse function used to turn strokes around, see `UnscramblePathOpts`.
+ */
+fn unscramble_reverse<T: StrokeBehavior + Clone + Hash + Eq>(opts: &Option<UnscramblePathOpts<T>>) -> fn(&T) -> T {
+  match opts {
+    Some(opt) => opt.reverse.unwrap_or(reverse_stroke),
+    None => reverse_stroke
+  }
+}
+
+/**
+ * Chain strokes together as in `unscramble_path`, recording whether each
+ * chain closes within the same tolerance.
+ */
+pub fn unscramble_paths<T: StrokeBehavior + Clone + Hash + Eq>(
+  strokes: Vec<T>,
+  opts: Option<UnscramblePathOpts<T>>
+) -> Vec<Path<T>> {
+  let tolerance = unscramble_tolerance(&strokes, &opts);
+  chain_strokes(strokes, unscramble_reverse(&opts), tolerance).into_iter()
+    .map(|chain| Path::new_unchecked(chain, tolerance))
+    .collect()
+}
+
 pub fn unscramble_path<T: StrokeBehavior + Clone + Hash + Eq>(
   strokes: Vec<T>, 
   opts: Option<UnscramblePathOpts<T>>
+) -> Vec<Vec<T>> {
+  let tolerance = unscramble_tolerance(&strokes, &opts);
+  chain_strokes(strokes, unscramble_reverse(&opts), tolerance)
+}
+
+/**
+ * Chain strokes whose ends lie within an absolute tolerance of each other.
+ */
+fn chain_strokes<T: StrokeBehavior + Clone + Hash + Eq>(
+  strokes: Vec<T>,
+  reverse: fn(&T) -> T,
+  tolerance: f64
 ) -> Vec<Vec<T>> {
   #[derive(Hash, Eq, PartialEq, Clone)]
   struct IndexedData<T> {
@@ -22,18 +419,7 @@ pub fn unscramble_path<T: StrokeBehavior + Clone + Hash + Eq>(
     i: usize,
   }
 
-  // Default values
-  let reverse = match &opts {
-    Some(opt) => opt.reverse.unwrap_or(reverse_stroke),
-    None => reverse_stroke
-  };
-  let tolerance = match &opts {
-    Some(opt) => opt.tolerance.unwrap_or(0.001),
-    None => 0.001
-  };
-
-  let stroke_extremes = strokes.iter().map(|stroke| [stroke.get_p1(), stroke.get_p2()]).flatten().collect::<Vec<_>>();
-  let bounds = bounding_box_from_points(&stroke_extremes);
+  let bounds = path_bounding_box(&strokes);
 
   let mut index: Quadtree<QuadtreePoint<IndexedData<T>>, IndexedData<T>> = Quadtree::new(QuadtreeProps{
     bounds,
@@ -136,5 +522,5 @@ pub fn unscramble_path<T: StrokeBehavior + Clone + Hash + Eq>(
     }
   }
 
-  return result;
+  result
 }
\ No newline at end of file
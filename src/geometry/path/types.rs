@@ -0,0 +1,143 @@
+use std::slice::Iter;
+use crate::geometry::path::path::{path_continuity, path_tolerance};
+use crate::geometry::point::point::points_equal;
+use crate::geometry::types::error::GeometryError;
+use crate::geometry::types::stroke_types::StrokeBehavior;
+use crate::geometry::types::types::XY;
+use crate::utils::utils::Tolerance;
+
+/**
+ * An ordered chain of strokes, each starting where the previous one ends.
+ * A path is closed when its last stroke ends where its first one starts.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<T: StrokeBehavior> {
+  strokes: Vec<T>,
+  closed: bool,
+}
+
+impl<T: StrokeBehavior> Path<T> {
+  /**
+   * Create a path from ordered strokes, rejecting gaps between consecutive
+   * strokes larger than the tolerance. Whether the path is closed is
+   * decided with the same tolerance, whose relative part is scaled by the
+   * stroke ends as in `path_continuity`.
+   */
+  pub fn try_new(strokes: Vec<T>, tolerance: impl Into<Tolerance>) -> Result<Self, GeometryError> {
+    let tolerance = path_tolerance(&strokes, tolerance);
+    path_continuity(&strokes, tolerance)?;
+    Ok(Path::new_unchecked(strokes, tolerance))
+  }
+
+  /**
+   * Create a path from strokes already known to join within an absolute
+   * tolerance.
+   */
+  pub(crate) fn new_unchecked(strokes: Vec<T>, tolerance: f64) -> Self {
+    let closed = match (strokes.first(), strokes.last()) {
+      (Some(first), Some(last)) => points_equal(last.get_p2(), first.get_p1(), tolerance),
+      _ => false,
+    };
+    Path { strokes, closed }
+  }
+
+  pub fn strokes(&self) -> &[T] {
+    &self.strokes
+  }
+
+  pub fn into_strokes(self) -> Vec<T> {
+    self.strokes
+  }
+
+  pub fn len(&self) -> usize {
+    self.strokes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.strokes.is_empty()
+  }
+
+  pub fn is_closed(&self) -> bool {
+    self.closed
+  }
+
+  pub fn iter(&self) -> Iter<'_, T> {
+    self.strokes.iter()
+  }
+
+  /**
+   * Points where strokes start, followed by the end of the last stroke when
+   * the path is open. A closed path does not repeat its first vertex.
+   */
+  pub fn vertices(&self) -> impl Iterator<Item = XY> + '_ {
+    let end = if self.closed { None } else { self.strokes.last().map(|stroke| stroke.get_p2()) };
+    self.strokes.iter().map(|stroke| stroke.get_p1()).chain(end)
+  }
+}
+
+impl<'a, T: StrokeBehavior> IntoIterator for &'a Path<T> {
+  type Item = &'a T;
+  type IntoIter = Iter<'a, T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.strokes.iter()
+  }
+}
+
+impl<T: StrokeBehavior> IntoIterator for Path<T> {
+  type Item = T;
+  type IntoIter = std::vec::IntoIter<T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.strokes.into_iter()
+  }
+}
+
+/**
+ * A position along a path: the index of the stroke it falls on, the
+ * normalized parameter on that stroke, and the distance from the start of
+ * the path.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathPosition {
+  pub index: usize,
+  pub t: f64,
+  pub along: f64,
+}
+
+/**
+ * Point on a path nearest to some query, with its position along the path
+ * and its distance from the query.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathClosestPoint {
+  pub point: XY,
+  pub position: PathPosition,
+  pub distance: f64,
+}
+
+/**
+ * Rule deciding which points a path encloses when it winds around them more
+ * than once or in opposite directions.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+  /**
+   * Points the path winds around an odd number of times.
+   */
+  EvenOdd,
+  /**
+   * Points the path winds around any nonzero number of times.
+   */
+  NonZero,
+}
+
+/**
+ * Where a point lies relative to the region enclosed by a closed path.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Containment {
+  Inside,
+  Outside,
+  Boundary,
+}
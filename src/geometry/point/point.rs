@@ -1,6 +1,6 @@
 use crate::geometry::angle::angle::correct_angle_signs;
 use crate::geometry::types::types::{BoundingBox, XY};
-use crate::utils::utils::close_equal;
+use crate::utils::utils::{close_equal, Tolerance};
 
 pub fn create_point(x: f64, y: f64) -> XY {
   XY { x, y }
@@ -36,12 +36,32 @@ pub fn translate_point(translation: XY) -> impl Fn(XY) -> XY {
 }
 
 /**
- * Determine if two points are equal to one another.
+ * Determine if two points are equal to one another. The relative part of the
+ * tolerance is scaled by the larger point.
  */
-pub fn points_equal(p1: XY, p2: XY, tolerance: Option<f64>) -> bool {
+pub fn points_equal(p1: XY, p2: XY, tolerance: impl Into<Tolerance>) -> bool {
+  let tolerance = point_tolerance(&[p1, p2], tolerance);
   close_equal(p1.x, p2.x, tolerance) && close_equal(p1.y, p2.y, tolerance)
 }
 
+/**
+ * Largest coordinate magnitude among some points, the scale that the
+ * relative part of a tolerance applies to.
+ */
+pub fn points_scale(points: &[XY]) -> f64 {
+  points.iter()
+    .map(|p| p.x.abs().max(p.y.abs()))
+    .filter(|m| m.is_finite())
+    .fold(0.0, f64::max)
+}
+
+/**
+ * Absolute tolerance to use for geometry spanning the given points.
+ */
+pub fn point_tolerance(points: &[XY], tolerance: impl Into<Tolerance>) -> f64 {
+  tolerance.into().at_scale(points_scale(points))
+}
+
 /**
  * Curried version of points_equal.
  */
@@ -54,7 +74,7 @@ pub fn point_equals(p1: XY) -> impl Fn(XY) -> bool {
 /**
  * Create a bounding box from a list of points.
  */
-pub fn bounding_box_from_points(points: &Vec<XY>) -> BoundingBox {
+pub fn bounding_box_from_points(points: &[XY]) -> BoundingBox {
   let mut x_min = f64::INFINITY;
   let mut y_min = f64::INFINITY;
   let mut x_max = f64::NEG_INFINITY;
@@ -0,0 +1 @@
+pub mod predicates;
\ No newline at end of file
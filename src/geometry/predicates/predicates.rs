@@ -0,0 +1,206 @@
+use crate::geometry::types::types::{Orientation, XY};
+
+/**
+ * Half the machine epsilon, the largest relative rounding error of one operation.
+ */
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const CCW_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ICC_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/**
+ * Sum of two numbers as the rounded sum and its exact rounding error.
+ */
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+  let x = a + b;
+  let b_virtual = x - a;
+  let a_virtual = x - b_virtual;
+  (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/**
+ * Product of two numbers as the rounded product and its exact rounding error.
+ */
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+  let x = a * b;
+  (x, a.mul_add(b, -x))
+}
+
+/**
+ * Exact difference of two numbers as an expansion: a list of non-overlapping
+ * components in increasing order of magnitude, with zeros removed, whose
+ * sum is the exact value.
+ */
+fn diff_expansion(a: f64, b: f64) -> Vec<f64> {
+  let (x, error) = two_sum(a, -b);
+  [error, x].into_iter().filter(|c| *c != 0.0).collect()
+}
+
+/**
+ * Exact sum of an expansion and a number.
+ */
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+  let mut q = b;
+  let mut h = Vec::with_capacity(e.len() + 1);
+  for component in e {
+    let (sum, error) = two_sum(q, *component);
+    if error != 0.0 {
+      h.push(error);
+    }
+    q = sum;
+  }
+  if q != 0.0 {
+    h.push(q);
+  }
+  h
+}
+
+/**
+ * Exact sum of two expansions.
+ */
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+  f.iter().fold(e.to_vec(), |sum, component| grow_expansion(&sum, *component))
+}
+
+/**
+ * Exact product of an expansion and a number.
+ */
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+  let mut h = Vec::with_capacity(2 * e.len());
+  let mut q = 0.0;
+  for (i, component) in e.iter().enumerate() {
+    let (product, product_error) = two_product(*component, b);
+    if i == 0 {
+      if product_error != 0.0 {
+        h.push(product_error);
+      }
+      q = product;
+      continue;
+    }
+
+    let (sum, error) = two_sum(q, product_error);
+    if error != 0.0 {
+      h.push(error);
+    }
+    let (sum, error) = two_sum(product, sum);
+    if error != 0.0 {
+      h.push(error);
+    }
+    q = sum;
+  }
+  if q != 0.0 {
+    h.push(q);
+  }
+  h
+}
+
+/**
+ * Exact product of two expansions.
+ */
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+  f.iter().fold(vec![], |sum, component| expansion_sum(&sum, &scale_expansion(e, *component)))
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+  e.iter().map(|c| -c).collect()
+}
+
+/**
+ * The most significant component of an expansion, which has the sign of its
+ * exact value.
+ */
+fn most_significant(e: &[f64]) -> f64 {
+  e.last().copied().unwrap_or(0.0)
+}
+
+fn orient2d_exact(a: XY, b: XY, c: XY) -> f64 {
+  let acx = diff_expansion(a.x, c.x);
+  let acy = diff_expansion(a.y, c.y);
+  let bcx = diff_expansion(b.x, c.x);
+  let bcy = diff_expansion(b.y, c.y);
+
+  let left = expansion_product(&acx, &bcy);
+  let right = expansion_product(&acy, &bcx);
+  most_significant(&expansion_sum(&left, &negate(&right)))
+}
+
+/**
+ * Twice the signed area of the triangle a, b, c: positive when the points
+ * run counter-clockwise, negative when clockwise and zero when collinear.
+ * The sign is always exact; the magnitude is approximate when the points
+ * are nearly collinear.
+ */
+pub fn orient2d(a: XY, b: XY, c: XY) -> f64 {
+  let left = (a.x - c.x) * (b.y - c.y);
+  let right = (a.y - c.y) * (b.x - c.x);
+  let det = left - right;
+
+  // Most inputs are far from degenerate and the rounded result can be trusted.
+  let bound = CCW_ERROR_BOUND * (left.abs() + right.abs());
+  if det.abs() > bound || (left == 0.0 && right == 0.0) {
+    return det;
+  }
+
+  orient2d_exact(a, b, c)
+}
+
+/**
+ * Exact turning direction of the points a, b, c, or None when they are collinear.
+ */
+pub fn orientation(a: XY, b: XY, c: XY) -> Option<Orientation> {
+  let det = orient2d(a, b, c);
+  if det > 0.0 {
+    Some(Orientation::CounterClockwise)
+  } else if det < 0.0 {
+    Some(Orientation::Clockwise)
+  } else {
+    None
+  }
+}
+
+fn incircle_exact(a: XY, b: XY, c: XY, d: XY) -> f64 {
+  let adx = diff_expansion(a.x, d.x);
+  let ady = diff_expansion(a.y, d.y);
+  let bdx = diff_expansion(b.x, d.x);
+  let bdy = diff_expansion(b.y, d.y);
+  let cdx = diff_expansion(c.x, d.x);
+  let cdy = diff_expansion(c.y, d.y);
+
+  let cross = |px: &[f64], py: &[f64], qx: &[f64], qy: &[f64]| {
+    expansion_sum(&expansion_product(px, qy), &negate(&expansion_product(qx, py)))
+  };
+  let lift = |px: &[f64], py: &[f64]| expansion_sum(&expansion_product(px, px), &expansion_product(py, py));
+
+  let a_term = expansion_product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+  let b_term = expansion_product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+  let c_term = expansion_product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+  most_significant(&expansion_sum(&expansion_sum(&a_term, &b_term), &c_term))
+}
+
+/**
+ * Positive when d lies inside the circle through a, b and c, negative when
+ * outside and zero when on it, for a, b and c running counter-clockwise.
+ * The sign flips when they run clockwise. The sign is always exact; the
+ * magnitude is approximate when the points are nearly cocircular.
+ */
+pub fn incircle(a: XY, b: XY, c: XY, d: XY) -> f64 {
+  let (adx, ady) = (a.x - d.x, a.y - d.y);
+  let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+  let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+
+  let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+  let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+  let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+  let a_lift = adx * adx + ady * ady;
+  let b_lift = bdx * bdx + bdy * bdy;
+  let c_lift = cdx * cdx + cdy * cdy;
+
+  let det = a_lift * (bdxcdy - cdxbdy) + b_lift * (cdxady - adxcdy) + c_lift * (adxbdy - bdxady);
+  let permanent = (bdxcdy.abs() + cdxbdy.abs()) * a_lift
+    + (cdxady.abs() + adxcdy.abs()) * b_lift
+    + (adxbdy.abs() + bdxady.abs()) * c_lift;
+  if det.abs() > ICC_ERROR_BOUND * permanent || permanent == 0.0 {
+    return det;
+  }
+
+  incircle_exact(a, b, c, d)
+}
@@ -0,0 +1,4 @@
+pub mod types;
+pub mod point;
+pub mod plane;
+pub mod segment;
\ No newline at end of file
@@ -0,0 +1,144 @@
+use crate::geometry::space::point::{points_equal_3d, points_scale_3d};
+use crate::geometry::space::segment::segment_3d_point_at;
+use crate::geometry::space::types::{Plane, PlaneFrame, Segment3D};
+use crate::geometry::types::error::GeometryError;
+use crate::geometry::types::types::{Vector3D, XY, XYZ};
+use crate::utils::utils::{Tolerance, TOLERANCE};
+
+/**
+ * Create a plane through a point, normalizing the normal.
+ */
+pub fn plane_from_normal(origin: XYZ, normal: Vector3D) -> Result<Plane, GeometryError> {
+  let normal = normal.normalize().ok_or(GeometryError::DegenerateDirection)?;
+  Ok(Plane { origin, normal })
+}
+
+/**
+ * Create the plane through three points, facing so that they run
+ * counter-clockwise when viewed from the front. The plane's origin is p1.
+ */
+pub fn plane_from_points(p1: XYZ, p2: XYZ, p3: XYZ) -> Result<Plane, GeometryError> {
+  if points_equal_3d(p1, p2, None) || points_equal_3d(p2, p3, None) || points_equal_3d(p1, p3, None) {
+    return Err(GeometryError::CoincidentPoints);
+  }
+
+  let d1 = p2 - p1;
+  let d2 = p3 - p1;
+  let normal = d1.cross(d2);
+  if normal.length() <= TOLERANCE * d1.length() * d2.length() {
+    return Err(GeometryError::CollinearPoints);
+  }
+
+  plane_from_normal(p1, normal)
+}
+
+/**
+ * Distance of a point from a plane, positive on the side the normal faces.
+ */
+pub fn signed_distance_to_plane(point: XYZ, plane: &Plane) -> f64 {
+  (point - plane.origin).dot(plane.normal)
+}
+
+/**
+ * Foot of the perpendicular from a point onto a plane.
+ */
+pub fn project_point_to_plane(point: XYZ, plane: &Plane) -> XYZ {
+  point - plane.normal * signed_distance_to_plane(point, plane)
+}
+
+/**
+ * Point where an infinite line meets a plane, or None when the line runs
+ * parallel to it within tolerance, including lines lying in the plane. The
+ * tolerance applies to the cosine of the angle between the line and the
+ * normal, so its relative part is scaled by one.
+ */
+pub fn line_plane_intersection(
+  origin: XYZ,
+  direction: Vector3D,
+  plane: &Plane,
+  tolerance: impl Into<Tolerance>,
+) -> Option<XYZ> {
+  let tolerance = tolerance.into().at_scale(1.0);
+  let direction = direction.normalize()?;
+  let facing = direction.dot(plane.normal);
+  if facing.abs() < tolerance {
+    return None;
+  }
+
+  Some(origin - direction * (signed_distance_to_plane(origin, plane) / facing))
+}
+
+/**
+ * Point where a segment crosses a plane and its normalized parameter on the
+ * segment. Ends within tolerance of the plane count as crossing there. A
+ * segment lying in the plane has no single crossing and gives None. The
+ * relative part of the tolerance is scaled by the segment and plane origin.
+ */
+pub fn segment_plane_intersection(
+  segment: &Segment3D,
+  plane: &Plane,
+  tolerance: impl Into<Tolerance>,
+) -> Option<(XYZ, f64)> {
+  let tolerance = tolerance.into().at_scale(points_scale_3d(&[segment.p1, segment.p2, plane.origin]));
+  let h1 = signed_distance_to_plane(segment.p1, plane);
+  let h2 = signed_distance_to_plane(segment.p2, plane);
+
+  match (h1.abs() < tolerance, h2.abs() < tolerance) {
+    (true, true) => None,
+    (true, false) => Some((segment.p1, 0.0)),
+    (false, true) => Some((segment.p2, 1.0)),
+    (false, false) if (h1 < 0.0) == (h2 < 0.0) => None,
+    (false, false) => {
+      let t = h1 / (h1 - h2);
+      Some((segment_3d_point_at(segment, t), t))
+    }
+  }
+}
+
+/**
+ * Local coordinate frame on a plane. The x axis follows the given direction
+ * projected onto the plane. Without one it follows whichever world axis lies
+ * furthest from the normal, so that frames stay stable for a given plane.
+ */
+pub fn plane_frame(plane: &Plane, x_direction: Option<Vector3D>) -> Result<PlaneFrame, GeometryError> {
+  let normal = plane.normal.normalize().ok_or(GeometryError::DegenerateDirection)?;
+  let x_direction = x_direction.unwrap_or_else(|| {
+    let (x, y, z) = (normal.i.abs(), normal.j.abs(), normal.k.abs());
+    if x <= y && x <= z {
+      Vector3D::new(1.0, 0.0, 0.0)
+    } else if y <= z {
+      Vector3D::new(0.0, 1.0, 0.0)
+    } else {
+      Vector3D::new(0.0, 0.0, 1.0)
+    }
+  });
+
+  let in_plane = x_direction - normal * x_direction.dot(normal);
+  if in_plane.length() <= TOLERANCE * x_direction.length() {
+    return Err(GeometryError::DegenerateDirection);
+  }
+  let x_axis = in_plane.normalize().ok_or(GeometryError::DegenerateDirection)?;
+
+  Ok(PlaneFrame {
+    origin: plane.origin,
+    x_axis,
+    y_axis: normal.cross(x_axis),
+    normal,
+  })
+}
+
+/**
+ * Local coordinates of a point projected onto the plane of a frame. The
+ * distance off the plane is discarded.
+ */
+pub fn point_to_local(point: XYZ, frame: &PlaneFrame) -> XY {
+  let offset = point - frame.origin;
+  XY { x: offset.dot(frame.x_axis), y: offset.dot(frame.y_axis) }
+}
+
+/**
+ * Lift local coordinates on the plane of a frame back into 3D.
+ */
+pub fn point_to_world(point: XY, frame: &PlaneFrame) -> XYZ {
+  frame.origin + frame.x_axis * point.x + frame.y_axis * point.y
+}
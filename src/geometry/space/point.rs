@@ -0,0 +1,26 @@
+use crate::geometry::types::types::XYZ;
+use crate::utils::utils::{close_equal, Tolerance};
+
+pub fn p2p_dist_3d(p1: XYZ, p2: XYZ) -> f64 {
+  (p2 - p1).length()
+}
+
+/**
+ * Largest coordinate magnitude among some 3D points, the scale that the
+ * relative part of a tolerance applies to.
+ */
+pub fn points_scale_3d(points: &[XYZ]) -> f64 {
+  points.iter()
+    .map(|p| p.x.abs().max(p.y.abs()).max(p.z.abs()))
+    .filter(|m| m.is_finite())
+    .fold(0.0, f64::max)
+}
+
+/**
+ * Determine if two 3D points are equal to one another. The relative part of
+ * the tolerance is scaled by the larger point.
+ */
+pub fn points_equal_3d(p1: XYZ, p2: XYZ, tolerance: impl Into<Tolerance>) -> bool {
+  let tolerance = tolerance.into().at_scale(points_scale_3d(&[p1, p2]));
+  close_equal(p1.x, p2.x, tolerance) && close_equal(p1.y, p2.y, tolerance) && close_equal(p1.z, p2.z, tolerance)
+}
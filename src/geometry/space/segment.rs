@@ -0,0 +1,84 @@
+use crate::geometry::space::point::p2p_dist_3d;
+use crate::geometry::space::types::{ClosestPoint3D, Segment3D, SegmentDistance3D};
+use crate::geometry::types::types::XYZ;
+
+pub fn segment_3d_length(segment: &Segment3D) -> f64 {
+  p2p_dist_3d(segment.p1, segment.p2)
+}
+
+/**
+ * Point at the normalized parameter t along a 3D segment.
+ */
+pub fn segment_3d_point_at(segment: &Segment3D, t: f64) -> XYZ {
+  segment.p1 + (segment.p2 - segment.p1) * t
+}
+
+/**
+ * Point on a 3D segment nearest to the given point.
+ */
+pub fn closest_point_on_segment_3d(point: XYZ, segment: &Segment3D) -> ClosestPoint3D {
+  let direction = segment.p2 - segment.p1;
+  let length_squared = direction.length_squared();
+  let t = if length_squared == 0.0 {
+    0.0
+  } else {
+    ((point - segment.p1).dot(direction) / length_squared).clamp(0.0, 1.0)
+  };
+  let closest = segment_3d_point_at(segment, t);
+
+  ClosestPoint3D {
+    point: closest,
+    t,
+    distance: p2p_dist_3d(point, closest),
+  }
+}
+
+/**
+ * Shortest distance between two 3D segments, with the witness point on each.
+ * Parallel segments have many closest pairs; the one nearest the start of the
+ * first segment is used.
+ */
+pub fn segment_segment_distance_3d(s1: &Segment3D, s2: &Segment3D) -> SegmentDistance3D {
+  let d1 = s1.p2 - s1.p1;
+  let d2 = s2.p2 - s2.p1;
+  let r = s1.p1 - s2.p1;
+  let a = d1.length_squared();
+  let e = d2.length_squared();
+  let f = d2.dot(r);
+
+  let (t1, t2) = if a == 0.0 && e == 0.0 {
+    (0.0, 0.0)
+  } else if a == 0.0 {
+    (0.0, (f / e).clamp(0.0, 1.0))
+  } else {
+    let c = d1.dot(r);
+    if e == 0.0 {
+      ((-c / a).clamp(0.0, 1.0), 0.0)
+    } else {
+      let b = d1.dot(d2);
+      let denominator = a * e - b * b;
+      // Pick the closest point on the first segment to the second line, unless parallel.
+      let t1 = if denominator > 0.0 { ((b * f - c * e) / denominator).clamp(0.0, 1.0) } else { 0.0 };
+      let t2 = (b * t1 + f) / e;
+
+      // Clamp the second parameter and recompute the first to match.
+      if t2 < 0.0 {
+        ((-c / a).clamp(0.0, 1.0), 0.0)
+      } else if t2 > 1.0 {
+        (((b - c) / a).clamp(0.0, 1.0), 1.0)
+      } else {
+        (t1, t2)
+      }
+    }
+  };
+
+  let point1 = segment_3d_point_at(s1, t1);
+  let point2 = segment_3d_point_at(s2, t2);
+  SegmentDistance3D {
+    distance: p2p_dist_3d(point1, point2),
+    point1,
+    t1,
+    point2,
+    t2,
+  }
+}
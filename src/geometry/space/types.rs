@@ -0,0 +1,56 @@
+use serde::{Serialize, Deserialize};
+use crate::geometry::types::types::{Vector3D, XYZ};
+
+/**
+ * A straight line between two points in 3D.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Segment3D {
+  pub p1: XYZ,
+  pub p2: XYZ,
+}
+
+/**
+ * An infinite plane through an origin, facing along a unit normal.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Plane {
+  pub origin: XYZ,
+  pub normal: Vector3D,
+}
+
+/**
+ * Right-handed local coordinates on a plane. Local x and y run along the
+ * unit axes from the origin, and the normal is x_axis × y_axis.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PlaneFrame {
+  pub origin: XYZ,
+  pub x_axis: Vector3D,
+  pub y_axis: Vector3D,
+  pub normal: Vector3D,
+}
+
+/**
+ * Point on a 3D segment nearest to some query, along with its normalized
+ * parameter on the segment and its distance from the query.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClosestPoint3D {
+  pub point: XYZ,
+  pub t: f64,
+  pub distance: f64,
+}
+
+/**
+ * Shortest distance between two 3D segments, with the witness point on each
+ * segment and its normalized parameter there.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SegmentDistance3D {
+  pub distance: f64,
+  pub point1: XYZ,
+  pub t1: f64,
+  pub point2: XYZ,
+  pub t2: f64,
+}
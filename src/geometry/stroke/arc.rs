@@ -1,10 +1,613 @@
-use crate::geometry::types::stroke_types::ArcBehavior;
+use std::f64::consts::PI;
+use crate::geometry::angle::angle::{angle_range_contains, angle_range_offset, ccw_angle_difference};
+use crate::geometry::angle::types::{AngleRange, Radians};
+use crate::geometry::point::point::{bounding_box_from_points, p2p_angle, p2p_dist, point_tolerance, points_equal};
+use crate::geometry::stroke::utils::split_params;
+use crate::geometry::types::error::GeometryError;
+use crate::geometry::types::stroke_types::{Arc, ArcBehavior};
+use crate::geometry::types::types::{BoundingBox, Orientation, Vector2D, XY};
+use crate::utils::utils::{close_equal, Tolerance};
 
-pub fn reverse_arc<T: ArcBehavior>(arc: &T) -> T where T: Clone {
-  // Need to add condition for half circle to deal with major.
+/**
+ * Center of an arc. Strokes that are not arcs have no center, in which
+ * case the start point is used so that every derived quantity collapses to zero.
+ */
+pub(crate) fn arc_center<T: ArcBehavior>(arc: &T) -> XY {
+  arc.get_center().unwrap_or(arc.get_p1())
+}
+
+/**
+ * Counter-clockwise angle swept from p1 to p2 around the center, in [0, 2π).
+ */
+fn ccw_sweep<T: ArcBehavior>(arc: &T) -> f64 {
+  let center = arc_center(arc);
+  ccw_angle_difference(p2p_angle(center, arc.get_p1()), p2p_angle(center, arc.get_p2()))
+}
+
+/**
+ * Radius of an arc, measured from the center to p1.
+ */
+pub fn arc_radius<T: ArcBehavior>(arc: &T) -> f64 {
+  p2p_dist(arc_center(arc), arc.get_p1())
+}
+
+/**
+ * Direction an arc turns when travelling from p1 to p2. An explicit direction
+ * always wins. Otherwise the direction is implied by the major flag, and a
+ * half circle is taken as counter-clockwise.
+ */
+pub fn arc_direction<T: ArcBehavior>(arc: &T) -> Orientation {
+  if let Some(direction) = arc.get_direction() {
+    return direction;
+  }
+
+  let sweep = ccw_sweep(arc);
+  if close_equal(sweep, PI, None) {
+    return Orientation::CounterClockwise;
+  }
+
+  let ccw_is_major = sweep > PI;
+  if ccw_is_major == arc.get_major().unwrap_or(false) {
+    Orientation::CounterClockwise
+  } else {
+    Orientation::Clockwise
+  }
+}
+
+/**
+ * Unsigned angle swept by an arc from p1 to p2, in [0, 2π).
+ * Arcs whose endpoints coincide are degenerate and sweep zero.
+ */
+pub fn arc_sweep_angle<T: ArcBehavior>(arc: &T) -> f64 {
+  if points_equal(arc.get_p1(), arc.get_p2(), None) {
+    return 0.0;
+  }
+
+  let sweep = ccw_sweep(arc);
+  match arc_direction(arc) {
+    Orientation::CounterClockwise => sweep,
+    Orientation::Clockwise => 2.0 * PI - sweep,
+  }
+}
+
+/**
+ * Signed angle swept by an arc, positive when counter-clockwise.
+ */
+pub fn arc_signed_sweep_angle<T: ArcBehavior>(arc: &T) -> f64 {
+  let sweep = arc_sweep_angle(arc);
+  match arc_direction(arc) {
+    Orientation::CounterClockwise => sweep,
+    Orientation::Clockwise => -sweep,
+  }
+}
+
+/**
+ * Length of an arc along its curve.
+ */
+pub fn arc_length<T: ArcBehavior>(arc: &T) -> f64 {
+  arc_radius(arc) * arc_sweep_angle(arc)
+}
+
+/**
+ * Reverse the direction of travel of an arc. The swept curve is unchanged:
+ * an explicit direction is flipped, and a half circle without one gets an
+ * explicit direction so that it does not fall back to the default side.
+ */
+pub fn reverse_arc<T: ArcBehavior + Clone>(arc: &T) -> T {
   let mut reversed_arc = arc.clone();
   reversed_arc.set_p1(arc.get_p2());
   reversed_arc.set_p2(arc.get_p1());
 
-  return reversed_arc
-}
\ No newline at end of file
+  match arc.get_direction() {
+    Some(Orientation::Clockwise) => reversed_arc.set_direction(Orientation::CounterClockwise),
+    Some(Orientation::CounterClockwise) => reversed_arc.set_direction(Orientation::Clockwise),
+    None => {
+      if close_equal(ccw_sweep(arc), PI, None) {
+        reversed_arc.set_direction(Orientation::Clockwise);
+      }
+    }
+  }
+
+  reversed_arc
+}
+
+/**
+ * Polar angle of the point at the normalized parameter t along an arc,
+ * t is clamped to [0, 1].
+ */
+pub fn arc_angle_at<T: ArcBehavior>(arc: &T, t: f64) -> f64 {
+  let start = p2p_angle(arc_center(arc), arc.get_p1());
+  start + arc_signed_sweep_angle(arc) * t.clamp(0.0, 1.0)
+}
+
+/**
+ * Point at the normalized parameter t along an arc, t is clamped to [0, 1].
+ */
+pub fn arc_point_at<T: ArcBehavior>(arc: &T, t: f64) -> XY {
+  let t = t.clamp(0.0, 1.0);
+  // Return the stored endpoints exactly rather than recomputing them.
+  if t == 0.0 {
+    return arc.get_p1();
+  }
+  if t == 1.0 {
+    return arc.get_p2();
+  }
+
+  let center = arc_center(arc);
+  let radius = arc_radius(arc);
+  let angle = arc_angle_at(arc, t);
+  XY {
+    x: center.x + radius * angle.cos(),
+    y: center.y + radius * angle.sin(),
+  }
+}
+
+/**
+ * Normalized parameter of the point at the given distance from p1 along an arc.
+ */
+pub fn arc_param_at_distance<T: ArcBehavior>(arc: &T, distance: f64) -> f64 {
+  let length = arc_length(arc);
+  if length == 0.0 {
+    return 0.0;
+  }
+
+  (distance / length).clamp(0.0, 1.0)
+}
+
+/**
+ * Point at the given distance from p1 along an arc.
+ */
+pub fn arc_point_at_distance<T: ArcBehavior>(arc: &T, distance: f64) -> XY {
+  arc_point_at(arc, arc_param_at_distance(arc, distance))
+}
+
+/**
+ * Unit tangent at the normalized parameter t along an arc, pointing in the
+ * direction of travel from p1 to p2.
+ */
+pub fn arc_tangent_at<T: ArcBehavior>(arc: &T, t: f64) -> Vector2D {
+  let angle = arc_angle_at(arc, t);
+  match arc_direction(arc) {
+    Orientation::CounterClockwise => Vector2D { i: -angle.sin(), j: angle.cos() },
+    Orientation::Clockwise => Vector2D { i: angle.sin(), j: -angle.cos() },
+  }
+}
+
+/**
+ * Unit normal at the normalized parameter t along an arc, the tangent rotated
+ * a quarter turn counter-clockwise. It points to the center of counter-clockwise
+ * arcs and away from the center of clockwise arcs.
+ */
+pub fn arc_normal_at<T: ArcBehavior>(arc: &T, t: f64) -> Vector2D {
+  arc_tangent_at(arc, t).perp()
+}
+
+/**
+ * Range of polar angles around the center swept by an arc, starting at p1.
+ */
+pub fn arc_angle_range<T: ArcBehavior>(arc: &T) -> AngleRange {
+  AngleRange {
+    start: p2p_angle(arc_center(arc), arc.get_p1()),
+    sweep: arc_signed_sweep_angle(arc),
+  }
+}
+
+/**
+ * Determine if a polar angle around the center lies within the sweep of an arc.
+ */
+pub fn arc_contains_angle<T: ArcBehavior>(arc: &T, angle: f64, tolerance: impl Into<Tolerance>) -> bool {
+  angle_range_contains(&arc_angle_range(arc), angle, tolerance)
+}
+
+/**
+ * Normalized parameter of the point on an arc at the same polar angle as the
+ * given point. The result is not clamped: angles outside the sweep produce
+ * values below 0 or above 1, whichever end of the arc is nearer.
+ */
+pub fn arc_param_of_point<T: ArcBehavior>(arc: &T, point: XY) -> f64 {
+  let sweep = arc_sweep_angle(arc);
+  if sweep == 0.0 {
+    return 0.0;
+  }
+
+  let offset = angle_range_offset(&arc_angle_range(arc), p2p_angle(arc_center(arc), point));
+  if offset > sweep && 2.0 * PI - offset < offset - sweep {
+    (offset - 2.0 * PI) / sweep
+  } else {
+    offset / sweep
+  }
+}
+
+/**
+ * Points that bound an arc: its endpoints, plus the points where the arc
+ * reaches its furthest extent along either axis. An axis point within the
+ * default tolerance of an end is kept, so the bounds do not depend on which
+ * side of the axis rounding left that end.
+ */
+pub fn arc_extreme_points<T: ArcBehavior>(arc: &T) -> Vec<XY> {
+  let center = arc_center(arc);
+  let radius = arc_radius(arc);
+  let mut points = vec![arc.get_p1(), arc.get_p2()];
+
+  if arc_sweep_angle(arc) == 0.0 {
+    return points;
+  }
+
+  let axis_points = [
+    (0.0, XY { x: center.x + radius, y: center.y }),
+    (0.5 * PI, XY { x: center.x, y: center.y + radius }),
+    (PI, XY { x: center.x - radius, y: center.y }),
+    (1.5 * PI, XY { x: center.x, y: center.y - radius }),
+  ];
+  for (angle, point) in axis_points {
+    if arc_contains_angle(arc, angle, None) {
+      points.push(point);
+    }
+  }
+
+  points
+}
+
+/**
+ * Tight bounding box of an arc.
+ */
+pub fn arc_bounding_box<T: ArcBehavior>(arc: &T) -> BoundingBox {
+  bounding_box_from_points(&arc_extreme_points(arc))
+}
+
+/**
+ * Split an arc at the given normalized parameters. Parameters outside (0, 1)
+ * and repeated parameters are ignored. Every piece keeps the original center
+ * and turning direction, gets its own major flag, and keeps any attached data.
+ */
+pub fn split_arc<T: ArcBehavior + Clone>(arc: &T, params: &[f64]) -> Vec<T> {
+  let direction = arc_direction(arc);
+  let sweep = arc_sweep_angle(arc);
+  let params = split_params(params);
+
+  let mut bounds = vec![(0.0, arc.get_p1())];
+  bounds.extend(params.into_iter().map(|t| (t, arc_point_at(arc, t))));
+  bounds.push((1.0, arc.get_p2()));
+
+  bounds.windows(2).map(|ends| {
+    let (t1, p1) = ends[0];
+    let (t2, p2) = ends[1];
+    let piece_sweep = (t2 - t1) * sweep;
+
+    let mut piece = arc.clone();
+    piece.set_p1(p1);
+    piece.set_p2(p2);
+    piece.set_major(piece_sweep > PI);
+    // A half circle cannot imply its direction, so it must be explicit.
+    if close_equal(piece_sweep, PI, None) {
+      piece.set_direction(direction);
+    }
+    piece
+  }).collect()
+}
+
+/**
+ * Offset an arc by a signed distance, keeping its center and sweep. Positive
+ * distances move it to the left of its direction of travel, along its normal,
+ * which shrinks counter-clockwise arcs and grows clockwise ones. Offsets that
+ * would shrink the radius to zero or below are an error.
+ */
+pub fn offset_arc<T: ArcBehavior + Clone>(arc: &T, distance: f64) -> Result<T, GeometryError> {
+  let center = arc_center(arc);
+  let radius = arc_radius(arc);
+  let new_radius = match arc_direction(arc) {
+    Orientation::CounterClockwise => radius - distance,
+    Orientation::Clockwise => radius + distance,
+  };
+
+  if radius == 0.0 || new_radius <= 0.0 || close_equal(new_radius, 0.0, None) {
+    return Err(GeometryError::CollapsedArc { radius, offset: distance });
+  }
+
+  let scale = new_radius / radius;
+  let shift = |p: XY| XY {
+    x: center.x + (p.x - center.x) * scale,
+    y: center.y + (p.y - center.y) * scale,
+  };
+
+  let mut offset = arc.clone();
+  offset.set_p1(shift(arc.get_p1()));
+  offset.set_p2(shift(arc.get_p2()));
+  Ok(offset)
+}
+
+fn arc_with_sweep(p1: XY, p2: XY, center: XY, sweep: f64) -> Arc {
+  Arc {
+    p1,
+    p2,
+    center,
+    major: Some(sweep.abs() > PI),
+    direction: Some(if sweep < 0.0 { Orientation::Clockwise } else { Orientation::CounterClockwise }),
+  }
+}
+
+/**
+ * Create the arc that starts at p1, passes through a second point and ends at p2.
+ */
+pub fn arc_from_three_points(p1: XY, through: XY, p2: XY) -> Result<Arc, GeometryError> {
+  if points_equal(p1, p2, None) || points_equal(p1, through, None) || points_equal(through, p2, None) {
+    return Err(GeometryError::CoincidentPoints);
+  }
+
+  let (bx, by) = (through.x - p1.x, through.y - p1.y);
+  let (cx, cy) = (p2.x - p1.x, p2.y - p1.y);
+  let d = 2.0 * (bx * cy - by * cx);
+  if close_equal(d / (p2p_dist(p1, through) * p2p_dist(p1, p2)), 0.0, None) {
+    return Err(GeometryError::CollinearPoints);
+  }
+
+  let b_sq = bx * bx + by * by;
+  let c_sq = cx * cx + cy * cy;
+  let center = XY {
+    x: p1.x + (cy * b_sq - by * c_sq) / d,
+    y: p1.y + (bx * c_sq - cx * b_sq) / d,
+  };
+
+  // p1, through and p2 turn left when the arc runs counter-clockwise.
+  let ccw_sweep = ccw_angle_difference(p2p_angle(center, p1), p2p_angle(center, p2));
+  let sweep = if d > 0.0 { ccw_sweep } else { ccw_sweep - 2.0 * PI };
+  Ok(arc_with_sweep(p1, p2, center, sweep))
+}
+
+/**
+ * Create an arc from its endpoints and a DXF style bulge, the tangent of a
+ * quarter of the sweep angle. Positive bulges turn counter-clockwise.
+ */
+pub fn arc_from_bulge(p1: XY, p2: XY, bulge: f64) -> Result<Arc, GeometryError> {
+  if points_equal(p1, p2, None) {
+    return Err(GeometryError::CoincidentPoints);
+  }
+  if bulge == 0.0 || !bulge.is_finite() {
+    return Err(GeometryError::InvalidSweep { sweep: 4.0 * bulge.atan() });
+  }
+
+  let chord = p2p_dist(p1, p2);
+  let (ux, uy) = ((p2.x - p1.x) / chord, (p2.y - p1.y) / chord);
+  // Signed distance from the middle of the chord to the center, to the left.
+  let offset = 0.5 * chord * (1.0 - bulge * bulge) / (2.0 * bulge);
+  let center = XY {
+    x: 0.5 * (p1.x + p2.x) - uy * offset,
+    y: 0.5 * (p1.y + p2.y) + ux * offset,
+  };
+
+  Ok(arc_with_sweep(p1, p2, center, 4.0 * bulge.atan()))
+}
+
+/**
+ * Create an arc from its endpoints and center, rejecting it when
+ * `validate_arc` finds it inconsistent.
+ */
+pub fn arc_from_center(
+  p1: XY,
+  p2: XY,
+  center: XY,
+  major: Option<bool>,
+  direction: Option<Orientation>,
+) -> Result<Arc, GeometryError> {
+  let arc = Arc { p1, p2, center, major, direction };
+  validate_arc(&arc, None).map(|_| arc)
+}
+
+/**
+ * Create an arc from its endpoints, radius, turning direction and whether it
+ * is the major arc. These pick the side of the chord the center lies on.
+ */
+pub fn arc_from_radius(
+  p1: XY,
+  p2: XY,
+  radius: f64,
+  direction: Orientation,
+  major: bool,
+) -> Result<Arc, GeometryError> {
+  if radius <= 0.0 || !radius.is_finite() {
+    return Err(GeometryError::InvalidRadius { radius });
+  }
+  if points_equal(p1, p2, None) {
+    return Err(GeometryError::CoincidentPoints);
+  }
+
+  let half_chord = 0.5 * p2p_dist(p1, p2);
+  if radius < half_chord && !close_equal(radius, half_chord, None) {
+    return Err(GeometryError::RadiusTooSmall { radius, minimum: half_chord });
+  }
+
+  let (ux, uy) = ((p2.x - p1.x) / (2.0 * half_chord), (p2.y - p1.y) / (2.0 * half_chord));
+  let distance = (radius * radius - half_chord * half_chord).max(0.0).sqrt();
+  // Minor counter-clockwise and major clockwise arcs have their center to the left of the chord.
+  let left = (direction == Orientation::CounterClockwise) != major;
+  let offset = if left { distance } else { -distance };
+  let center = XY {
+    x: 0.5 * (p1.x + p2.x) - uy * offset,
+    y: 0.5 * (p1.y + p2.y) + ux * offset,
+  };
+
+  Ok(Arc {
+    p1,
+    p2,
+    center,
+    major: Some(major),
+    direction: Some(direction),
+  })
+}
+
+/**
+ * Create an arc from its center, radius, start angle and signed sweep angle,
+ * positive sweeps turning counter-clockwise.
+ */
+pub fn arc_from_center_angles(
+  center: XY,
+  radius: f64,
+  start_angle: impl Into<Radians>,
+  sweep: impl Into<Radians>,
+) -> Result<Arc, GeometryError> {
+  let (start_angle, sweep) = (start_angle.into().0, sweep.into().0);
+  if radius <= 0.0 || !radius.is_finite() {
+    return Err(GeometryError::InvalidRadius { radius });
+  }
+  if !sweep.is_finite() || close_equal(sweep, 0.0, None) || sweep.abs() >= 2.0 * PI || close_equal(sweep.abs(), 2.0 * PI, None) {
+    return Err(GeometryError::InvalidSweep { sweep });
+  }
+
+  let end_angle = start_angle + sweep;
+  let p1 = XY { x: center.x + radius * start_angle.cos(), y: center.y + radius * start_angle.sin() };
+  let p2 = XY { x: center.x + radius * end_angle.cos(), y: center.y + radius * end_angle.sin() };
+  Ok(arc_with_sweep(p1, p2, center, sweep))
+}
+
+/**
+ * DXF style bulge of an arc, the tangent of a quarter of its signed sweep.
+ */
+pub fn arc_bulge<T: ArcBehavior>(arc: &T) -> f64 {
+  (arc_signed_sweep_angle(arc) / 4.0).tan()
+}
+
+/**
+ * Center, radius, start angle and signed sweep angle of an arc.
+ */
+pub fn arc_center_angles<T: ArcBehavior>(arc: &T) -> (XY, f64, f64, f64) {
+  let center = arc_center(arc);
+  (center, arc_radius(arc), p2p_angle(center, arc.get_p1()), arc_signed_sweep_angle(arc))
+}
+
+/**
+ * Check that an arc is well formed: finite coordinates, distinct endpoints,
+ * a non-zero radius, and both endpoints the same distance from the center.
+ * The relative part of the tolerance is scaled by the arc's coordinates.
+ */
+pub fn validate_arc<T: ArcBehavior>(arc: &T, tolerance: impl Into<Tolerance>) -> Result<(), GeometryError> {
+  let center = arc_center(arc);
+  let tolerance = point_tolerance(&[arc.get_p1(), arc.get_p2(), center], tolerance);
+  let finite = |p: XY| p.x.is_finite() && p.y.is_finite();
+  if !finite(arc.get_p1()) || !finite(arc.get_p2()) || !finite(center) {
+    return Err(GeometryError::NonFiniteCoordinate);
+  }
+  if points_equal(arc.get_p1(), arc.get_p2(), tolerance) {
+    return Err(GeometryError::CoincidentPoints);
+  }
+
+  let r1 = p2p_dist(center, arc.get_p1());
+  let r2 = p2p_dist(center, arc.get_p2());
+  if close_equal(r1, 0.0, tolerance) {
+    return Err(GeometryError::InvalidRadius { radius: r1 });
+  }
+  if !close_equal(r1, r2, tolerance) {
+    return Err(GeometryError::InconsistentRadii { r1, r2 });
+  }
+
+  Ok(())
+}
+
+/**
+ * Make an arc consistent: the center is moved onto the perpendicular bisector
+ * of its endpoints and the major flag is set explicitly, keeping the direction
+ * the arc turned in before. Half circles also get an explicit direction.
+ * Arcs with non-finite coordinates or coincident endpoints cannot be fixed.
+ */
+pub fn normalize_arc<T: ArcBehavior + Clone>(arc: &T) -> Result<T, GeometryError> {
+  match validate_arc(arc, None) {
+    Err(GeometryError::NonFiniteCoordinate) => return Err(GeometryError::NonFiniteCoordinate),
+    Err(GeometryError::CoincidentPoints) => return Err(GeometryError::CoincidentPoints),
+    _ => {}
+  }
+
+  let direction = arc_direction(arc);
+  let p1 = arc.get_p1();
+  let p2 = arc.get_p2();
+  let center = arc_center(arc);
+
+  // Project the center onto the bisector, which runs through the middle of
+  // the chord perpendicular to it.
+  let mid = XY { x: 0.5 * (p1.x + p2.x), y: 0.5 * (p1.y + p2.y) };
+  let chord = p2p_dist(p1, p2);
+  let (nx, ny) = (-(p2.y - p1.y) / chord, (p2.x - p1.x) / chord);
+  let along = (center.x - mid.x) * nx + (center.y - mid.y) * ny;
+
+  let mut normalized = arc.clone();
+  normalized.set_center(XY { x: mid.x + along * nx, y: mid.y + along * ny });
+
+  // Measure the sweep in the original direction, since moving the center
+  // can change which way round is the major arc.
+  let mut pinned = normalized.clone();
+  pinned.set_direction(direction);
+  let sweep = arc_sweep_angle(&pinned);
+
+  normalized.set_major(sweep > PI);
+  if close_equal(sweep, PI, None) {
+    normalized.set_direction(direction);
+  }
+
+  Ok(normalized)
+}
+
+/**
+ * Limits on how closely straight segments must follow an arc when it is
+ * tessellated. The maximum deviation bounds the gap between each chord and
+ * the arc, with its relative part scaled by the arc radius. The maximum
+ * angle bounds the angle swept by each chord. When neither is given, a
+ * deviation of 0.001 is used.
+ *
+ * Limits are only met up to MAX_TESSELLATION_COUNT chords per arc. Past
+ * that, chords deviate and sweep further than asked.
+ */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TessellateOpts {
+  /**
+   * Was `Option<f64>`, wrap absolute values with `Tolerance::absolute`.
+   */
+  pub max_deviation: Option<Tolerance>,
+  pub max_angle: Option<f64>,
+}
+
+/**
+ * Upper bound on the number of chords an arc is tessellated into, so tiny
+ * limits or huge radii cannot ask for an unbounded allocation. Arcs that
+ * need more chords are tessellated coarser than their limits.
+ */
+pub const MAX_TESSELLATION_COUNT: usize = 65536;
+
+/**
+ * Number of chords needed to follow an arc within the given limits, capped
+ * at MAX_TESSELLATION_COUNT.
+ */
+pub fn arc_tessellation_count<T: ArcBehavior>(arc: &T, opts: Option<TessellateOpts>) -> usize {
+  let opts = opts.unwrap_or_default();
+  let sweep = arc_sweep_angle(arc);
+  let radius = arc_radius(arc);
+  let positive = |value: Option<f64>| value.filter(|v| *v > 0.0);
+
+  let max_angle = positive(opts.max_angle);
+  let deviation = opts.max_deviation.map(|tolerance| tolerance.at_scale(radius));
+  let max_deviation = match (positive(deviation), max_angle) {
+    (Some(deviation), _) => Some(deviation),
+    (None, None) => Some(0.001),
+    (None, Some(_)) => None,
+  };
+
+  let mut count = 1.0_f64;
+  if let Some(deviation) = max_deviation {
+    // A chord sweeping θ deviates from its arc by r(1 - cos(θ/2)).
+    if deviation < radius {
+      let step = 2.0 * (1.0 - deviation / radius).acos();
+      count = count.max((sweep / step).ceil());
+    }
+  }
+  if let Some(step) = max_angle {
+    count = count.max((sweep / step).ceil());
+  }
+
+  count.min(MAX_TESSELLATION_COUNT as f64) as usize
+}
+
+/**
+ * Points of the polyline that follows an arc within the given limits,
+ * starting at p1 and ending at p2.
+ */
+pub fn tessellate_arc<T: ArcBehavior>(arc: &T, opts: Option<TessellateOpts>) -> Vec<XY> {
+  let count = arc_tessellation_count(arc, opts);
+  (0..=count).map(|i| arc_point_at(arc, i as f64 / count as f64)).collect()
+}
@@ -1,3 +1,4 @@
 pub mod arc;
 pub mod stroke;
-pub mod segment;
\ No newline at end of file
+pub mod segment;
+pub mod utils;
\ No newline at end of file
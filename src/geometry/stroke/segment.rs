@@ -1,4 +1,8 @@
+use crate::geometry::point::point::{bounding_box_from_points, p2p_dist};
+use crate::geometry::stroke::utils::split_params;
+use crate::geometry::types::error::GeometryError;
 use crate::geometry::types::stroke_types::SegmentBehavior;
+use crate::geometry::types::types::{BoundingBox, Vector2D, XY};
 
 pub fn reverse_segment<T: SegmentBehavior>(segment: &T) -> T where T: Clone {
     let mut reversed_segment = segment.clone();
@@ -6,4 +10,131 @@ pub fn reverse_segment<T: SegmentBehavior>(segment: &T) -> T where T: Clone {
     reversed_segment.set_p2(segment.get_p1());
 
     return reversed_segment
-}
\ No newline at end of file
+}
+
+/**
+ * Length of a segment.
+ */
+pub fn segment_length<T: SegmentBehavior>(segment: &T) -> f64 {
+    p2p_dist(segment.get_p1(), segment.get_p2())
+}
+
+/**
+ * Point at the normalized parameter t along a segment, t is clamped to [0, 1].
+ */
+pub fn segment_point_at<T: SegmentBehavior>(segment: &T, t: f64) -> XY {
+    let t = t.clamp(0.0, 1.0);
+    let p1 = segment.get_p1();
+    let p2 = segment.get_p2();
+
+    XY {
+        x: p1.x + (p2.x - p1.x) * t,
+        y: p1.y + (p2.y - p1.y) * t,
+    }
+}
+
+/**
+ * Point at the given distance from p1 along a segment.
+ */
+pub fn segment_point_at_distance<T: SegmentBehavior>(segment: &T, distance: f64) -> XY {
+    segment_point_at(segment, segment_param_at_distance(segment, distance))
+}
+
+/**
+ * Normalized parameter of the point at the given distance from p1 along a segment.
+ */
+pub fn segment_param_at_distance<T: SegmentBehavior>(segment: &T, distance: f64) -> f64 {
+    let length = segment_length(segment);
+    if length == 0.0 {
+        return 0.0;
+    }
+
+    (distance / length).clamp(0.0, 1.0)
+}
+
+/**
+ * Unit tangent of a segment, pointing from p1 to p2. The parameter is accepted
+ * for symmetry with arcs. A zero length segment has a zero tangent.
+ */
+pub fn segment_tangent_at<T: SegmentBehavior>(segment: &T, _t: f64) -> Vector2D {
+    (segment.get_p2() - segment.get_p1()).normalize().unwrap_or(Vector2D::zero())
+}
+
+/**
+ * Unit normal of a segment, the tangent rotated a quarter turn counter-clockwise.
+ */
+pub fn segment_normal_at<T: SegmentBehavior>(segment: &T, t: f64) -> Vector2D {
+    segment_tangent_at(segment, t).perp()
+}
+
+/**
+ * Bounding box of a segment.
+ */
+pub fn segment_bounding_box<T: SegmentBehavior>(segment: &T) -> BoundingBox {
+    bounding_box_from_points(&[segment.get_p1(), segment.get_p2()])
+}
+
+/**
+ * Normalized parameter of the projection of a point onto the infinite line
+ * through a segment. The result is not clamped, so points beyond the ends of
+ * the segment produce values outside [0, 1].
+ */
+pub fn segment_param_of_point<T: SegmentBehavior>(segment: &T, point: XY) -> f64 {
+    let p1 = segment.get_p1();
+    let p2 = segment.get_p2();
+    let dx = p2.x - p1.x;
+    let dy = p2.y - p1.y;
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0.0 {
+        return 0.0;
+    }
+
+    ((point.x - p1.x) * dx + (point.y - p1.y) * dy) / length_squared
+}
+
+/**
+ * Split a segment at the given normalized parameters. Parameters outside
+ * (0, 1) and repeated parameters are ignored. Every piece is a copy of the
+ * original with new endpoints, so any attached data is kept.
+ */
+pub fn split_segment<T: SegmentBehavior + Clone>(segment: &T, params: &[f64]) -> Vec<T> {
+    let mut boundaries = vec![segment.get_p1()];
+    boundaries.extend(split_params(params).into_iter().map(|t| segment_point_at(segment, t)));
+    boundaries.push(segment.get_p2());
+
+    boundaries.windows(2).map(|ends| {
+        let mut piece = segment.clone();
+        piece.set_p1(ends[0]);
+        piece.set_p2(ends[1]);
+        piece
+    }).collect()
+}
+
+/**
+ * Shift a segment sideways by a signed distance. Positive distances move it
+ * to the left of its direction of travel, along its normal.
+ */
+pub fn offset_segment<T: SegmentBehavior + Clone>(segment: &T, distance: f64) -> T {
+    let normal = segment_normal_at(segment, 0.0);
+    let shift = |p: XY| XY {
+        x: p.x + normal.i * distance,
+        y: p.y + normal.j * distance,
+    };
+
+    let mut offset = segment.clone();
+    offset.set_p1(shift(segment.get_p1()));
+    offset.set_p2(shift(segment.get_p2()));
+    offset
+}
+
+/**
+ * Check that a segment has finite coordinates.
+ */
+pub fn validate_segment<T: SegmentBehavior>(segment: &T) -> Result<(), GeometryError> {
+    let finite = |p: XY| p.x.is_finite() && p.y.is_finite();
+    if finite(segment.get_p1()) && finite(segment.get_p2()) {
+        Ok(())
+    } else {
+        Err(GeometryError::NonFiniteCoordinate)
+    }
+}
@@ -1,6 +1,36 @@
-use crate::geometry::types::stroke_types::{AnnotatedStroke, StrokeBehavior, StrokeType};
-use crate::geometry::stroke::segment::reverse_segment;
-use crate::geometry::stroke::arc::reverse_arc;
+use crate::geometry::types::error::GeometryError;
+use crate::geometry::types::stroke_types::{AnnotatedStroke, Segment, Stroke, StrokeBehavior, StrokeType};
+use crate::geometry::types::types::{BoundingBox, Vector2D, XY};
+use crate::geometry::distance::stroke::closest_point_on_stroke;
+use crate::geometry::point::point::{point_tolerance, points_equal};
+use crate::utils::utils::Tolerance;
+use crate::geometry::stroke::segment::{
+    reverse_segment,
+    segment_bounding_box,
+    offset_segment,
+    segment_length,
+    segment_normal_at,
+    segment_param_at_distance,
+    segment_point_at,
+    segment_tangent_at,
+    split_segment,
+    validate_segment,
+};
+use crate::geometry::stroke::arc::{
+    arc_bounding_box,
+    arc_length,
+    arc_normal_at,
+    arc_param_at_distance,
+    arc_point_at,
+    arc_tangent_at,
+    normalize_arc,
+    offset_arc,
+    reverse_arc,
+    split_arc,
+    tessellate_arc,
+    validate_arc,
+    TessellateOpts,
+};
 
 pub fn reverse_stroke<T: StrokeBehavior>(stroke: &T) -> T where T: Clone {
     match stroke.get_type() {
@@ -13,6 +43,191 @@ pub fn reverse_stroke<T: StrokeBehavior>(stroke: &T) -> T where T: Clone {
     }
 }
 
+/**
+ * Length of a stroke along its curve.
+ */
+pub fn stroke_length<T: StrokeBehavior>(stroke: &T) -> f64 {
+    match stroke.get_type() {
+        StrokeType::Segment => segment_length(stroke),
+        StrokeType::Arc => arc_length(stroke),
+    }
+}
+
+/**
+ * Point at the normalized parameter t along a stroke, t is clamped to [0, 1].
+ */
+pub fn stroke_point_at<T: StrokeBehavior>(stroke: &T, t: f64) -> XY {
+    match stroke.get_type() {
+        StrokeType::Segment => segment_point_at(stroke, t),
+        StrokeType::Arc => arc_point_at(stroke, t),
+    }
+}
+
+/**
+ * Normalized parameter of the point at the given distance from p1 along a stroke.
+ */
+pub fn stroke_param_at_distance<T: StrokeBehavior>(stroke: &T, distance: f64) -> f64 {
+    match stroke.get_type() {
+        StrokeType::Segment => segment_param_at_distance(stroke, distance),
+        StrokeType::Arc => arc_param_at_distance(stroke, distance),
+    }
+}
+
+/**
+ * Point at the given distance from p1 along a stroke.
+ */
+pub fn stroke_point_at_distance<T: StrokeBehavior>(stroke: &T, distance: f64) -> XY {
+    stroke_point_at(stroke, stroke_param_at_distance(stroke, distance))
+}
+
+/**
+ * Unit tangent at the normalized parameter t along a stroke.
+ */
+pub fn stroke_tangent_at<T: StrokeBehavior>(stroke: &T, t: f64) -> Vector2D {
+    match stroke.get_type() {
+        StrokeType::Segment => segment_tangent_at(stroke, t),
+        StrokeType::Arc => arc_tangent_at(stroke, t),
+    }
+}
+
+/**
+ * Unit normal at the normalized parameter t along a stroke, the tangent
+ * rotated a quarter turn counter-clockwise.
+ */
+pub fn stroke_normal_at<T: StrokeBehavior>(stroke: &T, t: f64) -> Vector2D {
+    match stroke.get_type() {
+        StrokeType::Segment => segment_normal_at(stroke, t),
+        StrokeType::Arc => arc_normal_at(stroke, t),
+    }
+}
+
+/**
+ * Tight bounding box of a stroke, including any bulge of an arc past its endpoints.
+ */
+pub fn stroke_bounding_box<T: StrokeBehavior>(stroke: &T) -> BoundingBox {
+    match stroke.get_type() {
+        StrokeType::Segment => segment_bounding_box(stroke),
+        StrokeType::Arc => arc_bounding_box(stroke),
+    }
+}
+
+/**
+ * Split a stroke at the given normalized parameters. Every piece is a copy
+ * of the original, so annotated strokes keep their data on each piece.
+ */
+pub fn split_stroke<T: StrokeBehavior + Clone>(stroke: &T, params: &[f64]) -> Vec<T> {
+    match stroke.get_type() {
+        StrokeType::Segment => split_segment(stroke, params),
+        StrokeType::Arc => split_arc(stroke, params),
+    }
+}
+
+/**
+ * Split a stroke at the given points. Points further than the tolerance from
+ * the stroke, or within it of either end, are ignored. Each point is snapped
+ * to its projection on the stroke, which is used exactly for the shared ends
+ * of neighbouring pieces, so arc pieces stay on their circle.
+ */
+pub fn split_stroke_at_points<T: StrokeBehavior + Clone>(
+    stroke: &T,
+    points: &[XY],
+    tolerance: impl Into<Tolerance>,
+) -> Vec<T> {
+    let tolerance = point_tolerance(&[stroke.get_p1(), stroke.get_p2()], tolerance);
+    let mut cuts: Vec<(f64, XY)> = points.iter()
+        .filter(|point| {
+            !points_equal(**point, stroke.get_p1(), Some(tolerance))
+                && !points_equal(**point, stroke.get_p2(), Some(tolerance))
+        })
+        .map(|point| closest_point_on_stroke(*point, stroke))
+        .filter(|closest| closest.distance < tolerance)
+        .map(|closest| (closest.t, closest.point))
+        .collect();
+    cuts.sort_by(|a, b| a.0.total_cmp(&b.0));
+    cuts.dedup_by(|b, a| a.0 == b.0 || points_equal(a.1, b.1, Some(tolerance)));
+    cuts.retain(|(t, _)| *t > 0.0 && *t < 1.0);
+
+    let (params, snapped): (Vec<f64>, Vec<XY>) = cuts.into_iter().unzip();
+    let mut pieces = split_stroke(stroke, &params);
+    let ends: Vec<XY> = std::iter::once(stroke.get_p1())
+        .chain(snapped)
+        .chain(std::iter::once(stroke.get_p2()))
+        .collect();
+    debug_assert_eq!(pieces.len() + 1, ends.len());
+    for (piece, ends) in pieces.iter_mut().zip(ends.windows(2)) {
+        piece.set_p1(ends[0]);
+        piece.set_p2(ends[1]);
+    }
+
+    pieces
+}
+
+/**
+ * Offset a stroke by a signed distance, positive to the left of its direction
+ * of travel. Fails when an arc would collapse onto its center.
+ */
+pub fn offset_stroke<T: StrokeBehavior + Clone>(stroke: &T, distance: f64) -> Result<T, GeometryError> {
+    match stroke.get_type() {
+        StrokeType::Segment => Ok(offset_segment(stroke, distance)),
+        StrokeType::Arc => offset_arc(stroke, distance),
+    }
+}
+
+/**
+ * Check that a stroke is well formed. Segments only need finite coordinates,
+ * arcs are checked as in `validate_arc`.
+ */
+pub fn validate_stroke<T: StrokeBehavior>(stroke: &T, tolerance: impl Into<Tolerance>) -> Result<(), GeometryError> {
+    match stroke.get_type() {
+        StrokeType::Segment => validate_segment(stroke),
+        StrokeType::Arc => validate_arc(stroke, tolerance),
+    }
+}
+
+/**
+ * Make a stroke consistent. Segments are returned unchanged once validated,
+ * arcs are normalized as in `normalize_arc`.
+ */
+pub fn normalize_stroke<T: StrokeBehavior + Clone>(stroke: &T) -> Result<T, GeometryError> {
+    match stroke.get_type() {
+        StrokeType::Segment => validate_segment(stroke).map(|_| stroke.clone()),
+        StrokeType::Arc => normalize_arc(stroke),
+    }
+}
+
+/**
+ * Points of the polyline that follows a stroke, see `tessellate_arc`.
+ */
+pub fn tessellate_stroke<T: StrokeBehavior>(stroke: &T, opts: Option<TessellateOpts>) -> Vec<XY> {
+    match stroke.get_type() {
+        StrokeType::Segment => vec![stroke.get_p1(), stroke.get_p2()],
+        StrokeType::Arc => tessellate_arc(stroke, opts),
+    }
+}
+
+/**
+ * Replace a stroke with straight segments that follow it within the given limits.
+ */
+pub fn flatten_stroke<T: StrokeBehavior>(stroke: &T, opts: Option<TessellateOpts>) -> Vec<Segment> {
+    tessellate_stroke(stroke, opts)
+        .windows(2)
+        .map(|ends| Segment { p1: ends[0], p2: ends[1] })
+        .collect()
+}
+
+/**
+ * Replace an annotated stroke with straight segments that each carry its data.
+ */
+pub fn flatten_annotated_stroke<U: Clone>(
+    stroke: &AnnotatedStroke<U>,
+    opts: Option<TessellateOpts>,
+) -> Vec<AnnotatedStroke<U>> {
+    flatten_stroke(stroke, opts)
+        .into_iter()
+        .map(|segment| stroke_with_new_data(Stroke::Segment(segment), stroke.data.clone()))
+        .collect()
+}
+
 pub fn stroke_with_new_data<T: StrokeBehavior, U>(stroke: T, data: U) -> AnnotatedStroke<U> {
     let new_stroke = stroke.get_stroke();
     AnnotatedStroke {
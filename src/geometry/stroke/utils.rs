@@ -0,0 +1,9 @@
+/**
+ * Sort split parameters, keeping only distinct values strictly inside (0, 1).
+ */
+pub(crate) fn split_params(params: &[f64]) -> Vec<f64> {
+    let mut params: Vec<f64> = params.iter().copied().filter(|t| *t > 0.0 && *t < 1.0).collect();
+    params.sort_by(|a, b| a.total_cmp(b));
+    params.dedup();
+    params
+}
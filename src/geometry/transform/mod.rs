@@ -0,0 +1 @@
+pub mod transform;
\ No newline at end of file
@@ -0,0 +1,253 @@
+use std::f64::consts::PI;
+use crate::geometry::point::point::bounding_box_from_points;
+use crate::geometry::stroke::arc::{arc_direction, arc_sweep_angle};
+use crate::geometry::types::error::GeometryError;
+use crate::geometry::types::stroke_types::{AnnotatedStroke, Arc, ArcBehavior, Segment, Stroke};
+use crate::geometry::types::types::{BoundingBox, Circle, Orientation, Vector2D, XY};
+use crate::quadtree::utils::get_node_points;
+use crate::utils::utils::close_equal;
+
+/**
+ * A 2D affine transform, mapping (x, y) to
+ * (a * x + c * y + tx, b * x + d * y + ty).
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2D {
+  pub a: f64,
+  pub b: f64,
+  pub c: f64,
+  pub d: f64,
+  pub tx: f64,
+  pub ty: f64,
+}
+
+impl Default for Transform2D {
+  fn default() -> Self {
+    Transform2D::identity()
+  }
+}
+
+impl Transform2D {
+  pub fn identity() -> Self {
+    Transform2D { a: 1.0, b: 0.0, c: 0.0, d: 1.0, tx: 0.0, ty: 0.0 }
+  }
+
+  pub fn translation(dx: f64, dy: f64) -> Self {
+    Transform2D { tx: dx, ty: dy, ..Transform2D::identity() }
+  }
+
+  /**
+   * Counter-clockwise rotation by an angle in radians about the origin.
+   */
+  pub fn rotation(angle: f64) -> Self {
+    let (sin, cos) = angle.sin_cos();
+    Transform2D { a: cos, b: sin, c: -sin, d: cos, tx: 0.0, ty: 0.0 }
+  }
+
+  /**
+   * Counter-clockwise rotation by an angle in radians about a point.
+   */
+  pub fn rotation_about(center: XY, angle: f64) -> Self {
+    Transform2D::translation(-center.x, -center.y)
+      .then(&Transform2D::rotation(angle))
+      .then(&Transform2D::translation(center.x, center.y))
+  }
+
+  /**
+   * Uniform scale about the origin.
+   */
+  pub fn scale(factor: f64) -> Self {
+    Transform2D::scale_xy(factor, factor)
+  }
+
+  /**
+   * Scale about the origin by separate factors along each axis.
+   */
+  pub fn scale_xy(sx: f64, sy: f64) -> Self {
+    Transform2D { a: sx, d: sy, ..Transform2D::identity() }
+  }
+
+  /**
+   * Scale about a point by separate factors along each axis.
+   */
+  pub fn scale_about(center: XY, sx: f64, sy: f64) -> Self {
+    Transform2D::translation(-center.x, -center.y)
+      .then(&Transform2D::scale_xy(sx, sy))
+      .then(&Transform2D::translation(center.x, center.y))
+  }
+
+  /**
+   * Reflection across the line through two points.
+   */
+  pub fn mirror(p1: XY, p2: XY) -> Self {
+    let angle = (p2.y - p1.y).atan2(p2.x - p1.x);
+    let (sin, cos) = (2.0 * angle).sin_cos();
+    Transform2D::translation(-p1.x, -p1.y)
+      .then(&Transform2D { a: cos, b: sin, c: sin, d: -cos, tx: 0.0, ty: 0.0 })
+      .then(&Transform2D::translation(p1.x, p1.y))
+  }
+
+  /**
+   * The transform that applies this one and then the other.
+   */
+  pub fn then(&self, other: &Transform2D) -> Transform2D {
+    Transform2D {
+      a: other.a * self.a + other.c * self.b,
+      b: other.b * self.a + other.d * self.b,
+      c: other.a * self.c + other.c * self.d,
+      d: other.b * self.c + other.d * self.d,
+      tx: other.a * self.tx + other.c * self.ty + other.tx,
+      ty: other.b * self.tx + other.d * self.ty + other.ty,
+    }
+  }
+
+  pub fn determinant(&self) -> f64 {
+    self.a * self.d - self.b * self.c
+  }
+
+  /**
+   * Determine if the transform reverses orientation, turning
+   * counter-clockwise into clockwise.
+   */
+  pub fn is_mirroring(&self) -> bool {
+    self.determinant() < 0.0
+  }
+
+  /**
+   * Scale factor of a transform that keeps circles circular, or an error
+   * when it stretches them into ellipses or collapses them.
+   */
+  pub fn uniform_scale(&self) -> Result<f64, GeometryError> {
+    let column1 = self.a * self.a + self.b * self.b;
+    let column2 = self.c * self.c + self.d * self.d;
+    let skew = self.a * self.c + self.b * self.d;
+    let size = column1.max(column2);
+
+    if size == 0.0 || close_equal(self.determinant() / size, 0.0, None) {
+      return Err(GeometryError::SingularTransform);
+    }
+    if !close_equal(column1 / size, column2 / size, None) || !close_equal(skew / size, 0.0, None) {
+      return Err(GeometryError::NonUniformScale);
+    }
+
+    Ok(column1.sqrt())
+  }
+
+  pub fn apply_to_point(&self, p: XY) -> XY {
+    XY {
+      x: self.a * p.x + self.c * p.y + self.tx,
+      y: self.b * p.x + self.d * p.y + self.ty,
+    }
+  }
+
+  /**
+   * Vectors are free of position, so only the linear part applies.
+   */
+  pub fn apply_to_vector(&self, v: Vector2D) -> Vector2D {
+    Vector2D {
+      i: self.a * v.i + self.c * v.j,
+      j: self.b * v.i + self.d * v.j,
+    }
+  }
+}
+
+/**
+ * Geometry that can be moved by a Transform2D.
+ */
+pub trait Transformable: Sized {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError>;
+}
+
+impl Transformable for XY {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    Ok(transform.apply_to_point(*self))
+  }
+}
+
+impl Transformable for Vector2D {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    Ok(transform.apply_to_vector(*self))
+  }
+}
+
+impl Transformable for Segment {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    Ok(Segment {
+      p1: transform.apply_to_point(self.p1),
+      p2: transform.apply_to_point(self.p2),
+    })
+  }
+}
+
+/**
+ * Arcs only survive transforms that keep circles circular. Mirroring flips the
+ * turning direction: the major flag keeps implying it, but an explicit direction
+ * or a half circle, whose direction cannot be implied, is flipped explicitly.
+ */
+impl Transformable for Arc {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    transform.uniform_scale()?;
+
+    let mut arc = Arc {
+      p1: transform.apply_to_point(self.p1),
+      p2: transform.apply_to_point(self.p2),
+      center: transform.apply_to_point(self.center),
+      ..*self
+    };
+
+    if transform.is_mirroring() && (self.direction.is_some() || close_equal(arc_sweep_angle(self), PI, None)) {
+      arc.set_direction(match arc_direction(self) {
+        Orientation::Clockwise => Orientation::CounterClockwise,
+        Orientation::CounterClockwise => Orientation::Clockwise,
+      });
+    }
+
+    Ok(arc)
+  }
+}
+
+impl Transformable for Stroke {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    match self {
+      Stroke::Segment(segment) => segment.transform(transform).map(Stroke::Segment),
+      Stroke::Arc(arc) => arc.transform(transform).map(Stroke::Arc),
+    }
+  }
+}
+
+impl<T: Clone> Transformable for AnnotatedStroke<T> {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    Ok(AnnotatedStroke {
+      stroke: self.stroke.transform(transform)?,
+      data: self.data.clone(),
+    })
+  }
+}
+
+impl Transformable for Circle {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    let scale = transform.uniform_scale()?;
+    Ok(Circle {
+      center: transform.apply_to_point(self.center),
+      radius: self.radius * scale,
+    })
+  }
+}
+
+/**
+ * The result is the box around the transformed corners, so rotating a box
+ * generally makes it larger.
+ */
+impl Transformable for BoundingBox {
+  fn transform(&self, transform: &Transform2D) -> Result<Self, GeometryError> {
+    let corners: Vec<XY> = get_node_points(self).into_iter().map(|p| transform.apply_to_point(p)).collect();
+    Ok(bounding_box_from_points(&corners))
+  }
+}
+
+/**
+ * Transform every stroke of a path, failing if any one of them cannot be.
+ */
+pub fn transform_path<T: Transformable>(path: &[T], transform: &Transform2D) -> Result<Vec<T>, GeometryError> {
+  path.iter().map(|stroke| stroke.transform(transform)).collect()
+}
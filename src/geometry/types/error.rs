@@ -0,0 +1,115 @@
+use std::fmt;
+
+/**
+ * Errors raised by geometric operations that have no valid result.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeometryError {
+    /**
+     * Offsetting an arc towards its center by at least its radius.
+     */
+    CollapsedArc { radius: f64, offset: f64 },
+    /**
+     * Points that must be distinct coincide.
+     */
+    CoincidentPoints,
+    /**
+     * Points that must define a circle lie on a line.
+     */
+    CollinearPoints,
+    /**
+     * A radius that is zero, negative or not finite.
+     */
+    InvalidRadius { radius: f64 },
+    /**
+     * A radius too short to span the chord between two points.
+     */
+    RadiusTooSmall { radius: f64, minimum: f64 },
+    /**
+     * An arc sweeping no angle, or a full turn that its endpoints cannot describe.
+     */
+    InvalidSweep { sweep: f64 },
+    /**
+     * A coordinate that is NaN or infinite.
+     */
+    NonFiniteCoordinate,
+    /**
+     * Arc endpoints at different distances from the center.
+     */
+    InconsistentRadii { r1: f64, r2: f64 },
+    /**
+     * A transform that would turn circles into ellipses.
+     */
+    NonUniformScale,
+    /**
+     * A transform that collapses the plane onto a line or a point.
+     */
+    SingularTransform,
+    /**
+     * A direction that must be non-zero has no length, or is parallel to
+     * another direction it must be independent of.
+     */
+    DegenerateDirection,
+    /**
+     * Text that is not a recognized angle or bearing.
+     */
+    UnparsableAngle,
+    /**
+     * An angle outside the range allowed where it is used.
+     */
+    AngleOutOfRange { angle: f64 },
+    /**
+     * A bounding box whose minimum exceeds its maximum, or with a NaN bound.
+     */
+    InvalidBounds { x_min: f64, x_max: f64, y_min: f64, y_max: f64 },
+    /**
+     * Quadtree limits that leave no room for objects or levels.
+     */
+    InvalidQuadtreeProps { max_objects: usize, max_levels: usize },
+    /**
+     * A stroke in a path that does not start where the previous one ends.
+     */
+    DiscontinuousPath { index: usize, gap: f64 },
+    /**
+     * A path that must be closed ends away from where it starts.
+     */
+    OpenPath,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeometryError::CollapsedArc { radius, offset } => {
+                write!(f, "offset of {} collapses arc of radius {}", offset, radius)
+            }
+            GeometryError::CoincidentPoints => write!(f, "points must be distinct"),
+            GeometryError::CollinearPoints => write!(f, "points must not be collinear"),
+            GeometryError::InvalidRadius { radius } => write!(f, "invalid radius {}", radius),
+            GeometryError::RadiusTooSmall { radius, minimum } => {
+                write!(f, "radius {} is smaller than the minimum of {}", radius, minimum)
+            }
+            GeometryError::InvalidSweep { sweep } => write!(f, "invalid sweep angle {}", sweep),
+            GeometryError::NonFiniteCoordinate => write!(f, "coordinates must be finite"),
+            GeometryError::InconsistentRadii { r1, r2 } => {
+                write!(f, "arc endpoints are at different radii {} and {}", r1, r2)
+            }
+            GeometryError::NonUniformScale => write!(f, "transform does not scale uniformly"),
+            GeometryError::SingularTransform => write!(f, "transform is not invertible"),
+            GeometryError::DegenerateDirection => write!(f, "direction must be non-zero and independent"),
+            GeometryError::UnparsableAngle => write!(f, "text is not a valid angle"),
+            GeometryError::AngleOutOfRange { angle } => write!(f, "angle {} is out of range", angle),
+            GeometryError::InvalidBounds { x_min, x_max, y_min, y_max } => {
+                write!(f, "invalid bounds x {}..{}, y {}..{}", x_min, x_max, y_min, y_max)
+            }
+            GeometryError::InvalidQuadtreeProps { max_objects, max_levels } => {
+                write!(f, "quadtree needs positive limits, got {} objects and {} levels", max_objects, max_levels)
+            }
+            GeometryError::DiscontinuousPath { index, gap } => {
+                write!(f, "path stroke {} starts {} away from the end of the previous stroke", index, gap)
+            }
+            GeometryError::OpenPath => write!(f, "path must be closed"),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
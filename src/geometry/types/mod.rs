@@ -1,2 +1,3 @@
 pub mod types;
-pub mod stroke_types;
\ No newline at end of file
+pub mod stroke_types;
+pub mod error;
\ No newline at end of file
@@ -1,5 +1,5 @@
 use serde::{Serialize, Deserialize};
-use crate::geometry::types::types::XY;
+use crate::geometry::types::types::{Orientation, XY};
 
 pub enum StrokeType {
   Segment,
@@ -18,6 +18,14 @@ pub trait ArcBehavior: SegmentBehavior {
   fn get_major(&self) -> Option<bool>;
   fn set_center(&mut self, p: XY);
   fn set_major(&mut self, major: bool);
+  /**
+   * Explicit turning direction, if the type stores one. Types without it
+   * keep the direction implied by `get_major`.
+   */
+  fn get_direction(&self) -> Option<Orientation> {
+    None
+  }
+  fn set_direction(&mut self, _direction: Orientation) {}
 }
 
 pub trait StrokeBehavior: SegmentBehavior + ArcBehavior {
@@ -63,6 +71,23 @@ pub struct Arc {
   pub center: XY,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub major: Option<bool>,
+  /**
+   * Explicit turning direction from p1 to p2. When omitted the direction
+   * is implied by `major`, and a half circle is taken as counter-clockwise.
+   * Struct literals that predate this field must now set it; `Arc::new`
+   * builds an arc without naming it.
+   */
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub direction: Option<Orientation>,
+}
+
+impl Arc {
+  /**
+   * Create an arc whose direction is implied by `major`.
+   */
+  pub fn new(p1: XY, p2: XY, center: XY, major: Option<bool>) -> Self {
+    Arc { p1, p2, center, major, direction: None }
+  }
 }
 
 impl SegmentBehavior for Arc {
@@ -99,6 +124,14 @@ impl ArcBehavior for Arc {
   fn set_major(&mut self, major: bool) {
       self.major = Some(major);
   }
+
+  fn get_direction(&self) -> Option<Orientation> {
+      self.direction
+  }
+
+  fn set_direction(&mut self, direction: Orientation) {
+      self.direction = Some(direction);
+  }
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, Eq, PartialEq)]
@@ -168,6 +201,20 @@ impl ArcBehavior for Stroke {
           Stroke::Arc(arc) => arc.set_major(major),
       }
   }
+
+  fn get_direction(&self) -> Option<Orientation> {
+      match self {
+          Stroke::Segment(_) => None,
+          Stroke::Arc(arc) => arc.get_direction(),
+      }
+  }
+
+  fn set_direction(&mut self, direction: Orientation) {
+      match self {
+          Stroke::Segment(_) => {},
+          Stroke::Arc(arc) => arc.set_direction(direction),
+      }
+  }
 }
 
 impl StrokeBehavior for Stroke {
@@ -223,6 +270,14 @@ impl<T> ArcBehavior for AnnotatedStroke<T> {
   fn set_major(&mut self, major: bool) {
       self.stroke.set_major(major);
   }
+
+  fn get_direction(&self) -> Option<Orientation> {
+      self.stroke.get_direction()
+  }
+
+  fn set_direction(&mut self, direction: Orientation) {
+      self.stroke.set_direction(direction);
+  }
 }
 
 impl<T> StrokeBehavior for AnnotatedStroke<T> {
@@ -2,6 +2,8 @@ use std::hash::{Hash, Hasher};
 
 use serde::{Serialize, Deserialize};
 
+use crate::geometry::types::error::GeometryError;
+
 #[derive(Clone, Copy, Serialize, Deserialize, Debug)]
 pub struct XY {
     pub x: f64,
@@ -29,20 +31,20 @@ impl PartialEq for XY {
 // Implementing Eq is required when implementing Hash
 impl Eq for XY {} // This is safe because we're comparing bits
 
-#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
 pub struct XYZ {
     pub x: f64,
     pub y: f64,
     pub z: f64,
 }
 
-#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Vector2D {
     pub i: f64,
     pub j: f64,
 }
 
-#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Vector3D {
     pub i: f64,
     pub j: f64,
@@ -55,10 +57,60 @@ pub struct Circle {
     pub radius: f64,
 }
 
+impl Circle {
+    /**
+     * Create a circle, rejecting a non-finite center and a radius that is
+     * negative or not finite. A zero radius is allowed.
+     */
+    pub fn try_new(center: XY, radius: f64) -> Result<Self, GeometryError> {
+        if !center.x.is_finite() || !center.y.is_finite() {
+            return Err(GeometryError::NonFiniteCoordinate);
+        }
+        if !radius.is_finite() || radius < 0.0 {
+            return Err(GeometryError::InvalidRadius { radius });
+        }
+        Ok(Circle { center, radius })
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct BoundingBox {
     pub x_min: f64,
     pub x_max: f64,
     pub y_min: f64,
     pub y_max: f64,
-}
\ No newline at end of file
+}
+
+impl BoundingBox {
+    /**
+     * Create a bounding box, rejecting NaN bounds and minimums above their
+     * maximums. Infinite bounds are allowed, as are empty widths or heights.
+     */
+    pub fn try_new(x_min: f64, x_max: f64, y_min: f64, y_max: f64) -> Result<Self, GeometryError> {
+        let bounds = BoundingBox { x_min, x_max, y_min, y_max };
+        bounds.validate().map(|_| bounds)
+    }
+
+    /**
+     * Check the bounds as in `try_new`.
+     */
+    pub fn validate(&self) -> Result<(), GeometryError> {
+        let BoundingBox { x_min, x_max, y_min, y_max } = *self;
+        // Comparisons with NaN are false, so this also catches NaN bounds.
+        if x_min <= x_max && y_min <= y_max {
+            Ok(())
+        } else {
+            Err(GeometryError::InvalidBounds { x_min, x_max, y_min, y_max })
+        }
+    }
+}
+
+/**
+ * Winding direction of an arc or a closed contour.
+ */
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
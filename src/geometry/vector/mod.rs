@@ -0,0 +1 @@
+pub mod vector;
\ No newline at end of file
@@ -0,0 +1,341 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use crate::geometry::types::types::{Vector2D, Vector3D, XY, XYZ};
+
+impl Vector2D {
+  pub fn new(i: f64, j: f64) -> Self {
+    Vector2D { i, j }
+  }
+
+  pub fn zero() -> Self {
+    Vector2D { i: 0.0, j: 0.0 }
+  }
+
+  /**
+   * Unit vector pointing at an angle in radians, counter-clockwise from +x.
+   */
+  pub fn from_angle(angle: f64) -> Self {
+    let (sin, cos) = angle.sin_cos();
+    Vector2D { i: cos, j: sin }
+  }
+
+  /**
+   * Vector from one point to another.
+   */
+  pub fn between(from: XY, to: XY) -> Self {
+    to - from
+  }
+
+  pub fn dot(&self, other: Vector2D) -> f64 {
+    self.i * other.i + self.j * other.j
+  }
+
+  /**
+   * The z component of the 3D cross product. Positive when the other vector
+   * lies counter-clockwise of this one.
+   */
+  pub fn cross(&self, other: Vector2D) -> f64 {
+    self.i * other.j - self.j * other.i
+  }
+
+  pub fn length_squared(&self) -> f64 {
+    self.dot(*self)
+  }
+
+  pub fn length(&self) -> f64 {
+    self.i.hypot(self.j)
+  }
+
+  /**
+   * Unit vector in the same direction, or None for a zero vector.
+   */
+  pub fn normalize(&self) -> Option<Vector2D> {
+    let length = self.length();
+    if length == 0.0 || !length.is_finite() {
+      None
+    } else {
+      Some(*self / length)
+    }
+  }
+
+  /**
+   * The vector rotated a quarter turn counter-clockwise.
+   */
+  pub fn perp(&self) -> Vector2D {
+    Vector2D { i: -self.j, j: self.i }
+  }
+
+  /**
+   * The vector rotated counter-clockwise by an angle in radians.
+   */
+  pub fn rotate(&self, angle: f64) -> Vector2D {
+    let (sin, cos) = angle.sin_cos();
+    Vector2D {
+      i: self.i * cos - self.j * sin,
+      j: self.i * sin + self.j * cos,
+    }
+  }
+
+  /**
+   * Angle of the vector counter-clockwise from +x, in (-π, π].
+   */
+  pub fn angle(&self) -> f64 {
+    self.j.atan2(self.i)
+  }
+
+  /**
+   * Component of this vector along another, or None when projecting onto a
+   * zero vector.
+   */
+  pub fn project_onto(&self, other: Vector2D) -> Option<Vector2D> {
+    let length_squared = other.length_squared();
+    if length_squared == 0.0 {
+      None
+    } else {
+      Some(other * (self.dot(other) / length_squared))
+    }
+  }
+
+  /**
+   * Unsigned angle between two vectors, in [0, π]. The angle is 0 when either
+   * vector is zero.
+   */
+  pub fn angle_between(&self, other: Vector2D) -> f64 {
+    self.cross(other).abs().atan2(self.dot(other))
+  }
+
+  /**
+   * Angle to turn counter-clockwise from this vector to another, in (-π, π].
+   */
+  pub fn signed_angle_to(&self, other: Vector2D) -> f64 {
+    self.cross(other).atan2(self.dot(other))
+  }
+}
+
+impl Vector3D {
+  pub fn new(i: f64, j: f64, k: f64) -> Self {
+    Vector3D { i, j, k }
+  }
+
+  pub fn zero() -> Self {
+    Vector3D { i: 0.0, j: 0.0, k: 0.0 }
+  }
+
+  /**
+   * Vector from one point to another.
+   */
+  pub fn between(from: XYZ, to: XYZ) -> Self {
+    to - from
+  }
+
+  pub fn dot(&self, other: Vector3D) -> f64 {
+    self.i * other.i + self.j * other.j + self.k * other.k
+  }
+
+  /**
+   * Right-handed cross product, perpendicular to both vectors.
+   */
+  pub fn cross(&self, other: Vector3D) -> Vector3D {
+    Vector3D {
+      i: self.j * other.k - self.k * other.j,
+      j: self.k * other.i - self.i * other.k,
+      k: self.i * other.j - self.j * other.i,
+    }
+  }
+
+  pub fn length_squared(&self) -> f64 {
+    self.dot(*self)
+  }
+
+  pub fn length(&self) -> f64 {
+    self.length_squared().sqrt()
+  }
+
+  /**
+   * Unit vector in the same direction, or None for a zero vector.
+   */
+  pub fn normalize(&self) -> Option<Vector3D> {
+    let length = self.length();
+    if length == 0.0 || !length.is_finite() {
+      None
+    } else {
+      Some(*self / length)
+    }
+  }
+
+  /**
+   * Component of this vector along another, or None when projecting onto a
+   * zero vector.
+   */
+  pub fn project_onto(&self, other: Vector3D) -> Option<Vector3D> {
+    let length_squared = other.length_squared();
+    if length_squared == 0.0 {
+      None
+    } else {
+      Some(other * (self.dot(other) / length_squared))
+    }
+  }
+
+  /**
+   * Unsigned angle between two vectors, in [0, π]. The angle is 0 when either
+   * vector is zero.
+   */
+  pub fn angle_between(&self, other: Vector3D) -> f64 {
+    self.cross(other).length().atan2(self.dot(other))
+  }
+}
+
+impl Sub for XY {
+  type Output = Vector2D;
+
+  fn sub(self, other: XY) -> Vector2D {
+    Vector2D { i: self.x - other.x, j: self.y - other.y }
+  }
+}
+
+impl Add<Vector2D> for XY {
+  type Output = XY;
+
+  fn add(self, v: Vector2D) -> XY {
+    XY { x: self.x + v.i, y: self.y + v.j }
+  }
+}
+
+impl Sub<Vector2D> for XY {
+  type Output = XY;
+
+  fn sub(self, v: Vector2D) -> XY {
+    XY { x: self.x - v.i, y: self.y - v.j }
+  }
+}
+
+impl AddAssign<Vector2D> for XY {
+  fn add_assign(&mut self, v: Vector2D) {
+    *self = *self + v;
+  }
+}
+
+impl SubAssign<Vector2D> for XY {
+  fn sub_assign(&mut self, v: Vector2D) {
+    *self = *self - v;
+  }
+}
+
+impl Add for Vector2D {
+  type Output = Vector2D;
+
+  fn add(self, other: Vector2D) -> Vector2D {
+    Vector2D { i: self.i + other.i, j: self.j + other.j }
+  }
+}
+
+impl Sub for Vector2D {
+  type Output = Vector2D;
+
+  fn sub(self, other: Vector2D) -> Vector2D {
+    Vector2D { i: self.i - other.i, j: self.j - other.j }
+  }
+}
+
+impl Neg for Vector2D {
+  type Output = Vector2D;
+
+  fn neg(self) -> Vector2D {
+    Vector2D { i: -self.i, j: -self.j }
+  }
+}
+
+impl Mul<f64> for Vector2D {
+  type Output = Vector2D;
+
+  fn mul(self, scalar: f64) -> Vector2D {
+    Vector2D { i: self.i * scalar, j: self.j * scalar }
+  }
+}
+
+impl Mul<Vector2D> for f64 {
+  type Output = Vector2D;
+
+  fn mul(self, v: Vector2D) -> Vector2D {
+    v * self
+  }
+}
+
+impl Div<f64> for Vector2D {
+  type Output = Vector2D;
+
+  fn div(self, scalar: f64) -> Vector2D {
+    Vector2D { i: self.i / scalar, j: self.j / scalar }
+  }
+}
+
+impl Sub for XYZ {
+  type Output = Vector3D;
+
+  fn sub(self, other: XYZ) -> Vector3D {
+    Vector3D { i: self.x - other.x, j: self.y - other.y, k: self.z - other.z }
+  }
+}
+
+impl Add<Vector3D> for XYZ {
+  type Output = XYZ;
+
+  fn add(self, v: Vector3D) -> XYZ {
+    XYZ { x: self.x + v.i, y: self.y + v.j, z: self.z + v.k }
+  }
+}
+
+impl Sub<Vector3D> for XYZ {
+  type Output = XYZ;
+
+  fn sub(self, v: Vector3D) -> XYZ {
+    XYZ { x: self.x - v.i, y: self.y - v.j, z: self.z - v.k }
+  }
+}
+
+impl Add for Vector3D {
+  type Output = Vector3D;
+
+  fn add(self, other: Vector3D) -> Vector3D {
+    Vector3D { i: self.i + other.i, j: self.j + other.j, k: self.k + other.k }
+  }
+}
+
+impl Sub for Vector3D {
+  type Output = Vector3D;
+
+  fn sub(self, other: Vector3D) -> Vector3D {
+    Vector3D { i: self.i - other.i, j: self.j - other.j, k: self.k - other.k }
+  }
+}
+
+impl Neg for Vector3D {
+  type Output = Vector3D;
+
+  fn neg(self) -> Vector3D {
+    Vector3D { i: -self.i, j: -self.j, k: -self.k }
+  }
+}
+
+impl Mul<f64> for Vector3D {
+  type Output = Vector3D;
+
+  fn mul(self, scalar: f64) -> Vector3D {
+    Vector3D { i: self.i * scalar, j: self.j * scalar, k: self.k * scalar }
+  }
+}
+
+impl Mul<Vector3D> for f64 {
+  type Output = Vector3D;
+
+  fn mul(self, v: Vector3D) -> Vector3D {
+    v * self
+  }
+}
+
+impl Div<f64> for Vector3D {
+  type Output = Vector3D;
+
+  fn div(self, scalar: f64) -> Vector3D {
+    Vector3D { i: self.i / scalar, j: self.j / scalar, k: self.k / scalar }
+  }
+}
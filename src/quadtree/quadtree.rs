@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 use std::hash::Hash;
 
+use crate::geometry::types::error::GeometryError;
 use crate::geometry::types::types::BoundingBox;
 use crate::quadtree::utils::offset_node_bounds;
 use crate::quadtree::types::QuadTreeObject;
@@ -21,6 +22,30 @@ impl Default for QuadtreeProps {
   }
 }
 
+impl QuadtreeProps {
+  /**
+   * Create quadtree limits, rejecting invalid bounds and zero limits.
+   */
+  pub fn try_new(bounds: BoundingBox, max_objects: usize, max_levels: usize) -> Result<Self, GeometryError> {
+    let props = QuadtreeProps { bounds, max_objects, max_levels };
+    props.validate().map(|_| props)
+  }
+
+  /**
+   * Check the limits as in `try_new`.
+   */
+  pub fn validate(&self) -> Result<(), GeometryError> {
+    self.bounds.validate()?;
+    if self.max_objects == 0 || self.max_levels == 0 {
+      return Err(GeometryError::InvalidQuadtreeProps {
+        max_objects: self.max_objects,
+        max_levels: self.max_levels,
+      });
+    }
+    Ok(())
+  }
+}
+
 pub struct Quadtree<T, U> where T: QuadTreeObject<U> {
   pub bounds: BoundingBox,
   pub max_objects: usize,
@@ -45,6 +70,14 @@ where T: QuadTreeObject<U> + Hash + Eq {
     }
   }
 
+  /**
+   * Create a quadtree node, rejecting invalid props as in `QuadtreeProps::try_new`.
+   */
+  pub fn try_new(props: QuadtreeProps, level: usize) -> Result<Quadtree<T, U>, GeometryError> {
+    props.validate()?;
+    Ok(Quadtree::new(props, level))
+  }
+
   pub fn split(&mut self) -> () {
     let level = self.level + 1;
     let BoundingBox { x_min, x_max, y_min, y_max } = self.bounds;
@@ -1,8 +1,14 @@
+/**
+ * Default tolerance used when comparing floating point values.
+ */
+pub const TOLERANCE: f64 = 0.000001;
+
 /**
  * Check if two numbers are close to each other, under a certain tolerance.
+ * The relative part of the tolerance is scaled by the larger of the two.
  */
-pub fn close_equal(a: f64, b: f64, tolerance: Option<f64>) -> bool {
-    (a - b).abs() < tolerance.unwrap_or(0.000001)
+pub fn close_equal(a: f64, b: f64, tolerance: impl Into<Tolerance>) -> bool {
+    (a - b).abs() < tolerance.into().at_scale(a.abs().max(b.abs()))
 }
 
 /**
@@ -18,4 +24,78 @@ pub fn round_to_n_decimals(x: f64, n: u32) -> f64 {
  */
 pub fn round(x: f64) -> f64 {
     round_to_n_decimals(x, 6)
-}
\ No newline at end of file
+}
+
+/**
+ * How far apart two values may be while still being treated as equal. The
+ * allowed difference is the larger of the absolute part and the relative
+ * part scaled by the size of the values, so that the same tolerance suits
+ * both small and large drawings.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance { absolute: TOLERANCE, relative: 0.0 }
+    }
+}
+
+impl From<f64> for Tolerance {
+    fn from(absolute: f64) -> Self {
+        Tolerance::absolute(absolute)
+    }
+}
+
+/**
+ * An optional absolute tolerance, falling back to the default when absent.
+ */
+impl From<Option<f64>> for Tolerance {
+    fn from(absolute: Option<f64>) -> Self {
+        absolute.map_or_else(Tolerance::default, Tolerance::absolute)
+    }
+}
+
+impl From<&Tolerance> for Tolerance {
+    fn from(tolerance: &Tolerance) -> Self {
+        *tolerance
+    }
+}
+
+impl Tolerance {
+    pub fn new(absolute: f64, relative: f64) -> Self {
+        Tolerance { absolute, relative }
+    }
+
+    pub fn absolute(absolute: f64) -> Self {
+        Tolerance { absolute, relative: 0.0 }
+    }
+
+    /**
+     * Allowed difference between values of the given magnitude.
+     */
+    pub fn at_scale(&self, scale: f64) -> f64 {
+        self.absolute.max(self.relative * scale.abs())
+    }
+
+    /**
+     * Number of decimal places needed to resolve the absolute part.
+     */
+    pub fn decimals(&self) -> u32 {
+        if self.absolute > 0.0 {
+            (-self.absolute.log10()).ceil().max(0.0) as u32
+        } else {
+            0
+        }
+    }
+}
+
+/**
+ * Round a number to the decimal places resolved by a tolerance.
+ */
+pub fn round_within(x: f64, tolerance: impl Into<Tolerance>) -> f64 {
+    round_to_n_decimals(x, tolerance.into().decimals())
+}
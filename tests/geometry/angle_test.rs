@@ -0,0 +1,159 @@
+use std::f64::consts::PI;
+use rust_comp_geo::geometry::angle::angle::{
+  angle_difference,
+  angle_range_between,
+  angle_range_contains,
+  angle_range_end,
+  angle_range_offset,
+  ccw_angle_difference,
+  correct_angle_signs,
+  correct_angle_signs_within,
+  normalize_angle,
+  normalize_angle_signed,
+};
+use rust_comp_geo::geometry::angle::types::{AngleRange, Degrees, Radians};
+use rust_comp_geo::geometry::stroke::arc::{arc_angle_range, arc_contains_angle};
+use rust_comp_geo::geometry::types::stroke_types::Arc;
+use rust_comp_geo::geometry::types::types::{Orientation, XY};
+use rust_comp_geo::utils::utils::{close_equal, Tolerance};
+
+#[cfg(test)]
+mod normalization_tests {
+
+use super::*;
+
+  #[test]
+  fn test_normalize_angle() {
+    assert_eq!(normalize_angle(0.0), 0.0);
+    assert_eq!(normalize_angle(-0.0).to_bits(), 0.0_f64.to_bits());
+    assert!(close_equal(normalize_angle(-PI / 2.0), 1.5 * PI, None));
+    assert!(close_equal(normalize_angle(5.0 * PI), PI, None));
+    assert_eq!(normalize_angle(2.0 * PI), 0.0);
+    assert!(normalize_angle(-1e-20) < 2.0 * PI);
+  }
+
+  #[test]
+  fn test_normalize_angle_signed() {
+    assert_eq!(normalize_angle_signed(PI), PI);
+    assert_eq!(normalize_angle_signed(-PI), PI);
+    assert!(close_equal(normalize_angle_signed(1.5 * PI), -PI / 2.0, None));
+    assert!(close_equal(normalize_angle_signed(-2.5 * PI), -PI / 2.0, None));
+  }
+
+  #[test]
+  fn test_correct_angle_signs() {
+    // -π points the same way as π; it must not collapse to 0.
+    assert_eq!(correct_angle_signs(-PI), PI);
+    assert_eq!(correct_angle_signs(PI), PI);
+    assert_eq!(correct_angle_signs(-PI / 2.0), -PI / 2.0);
+    assert_eq!(correct_angle_signs(-0.0).to_bits(), 0.0_f64.to_bits());
+  }
+
+  #[test]
+  fn test_correct_angle_signs_within() {
+    let loose = Tolerance::absolute(0.01);
+    assert!(close_equal(correct_angle_signs_within(-PI + 0.001, loose), PI + 0.001, None));
+    assert_eq!(correct_angle_signs_within(-PI + 0.001, Tolerance::default()), -PI + 0.001);
+    assert_eq!(correct_angle_signs_within(-0.0, loose).to_bits(), 0.0_f64.to_bits());
+  }
+
+  #[test]
+  fn test_angle_differences() {
+    assert!(close_equal(angle_difference(0.1, 2.0 * PI - 0.1), -0.2, None));
+    assert!(close_equal(angle_difference(2.0 * PI - 0.1, 0.1), 0.2, None));
+    assert!(close_equal(ccw_angle_difference(0.1, 2.0 * PI - 0.1), 2.0 * PI - 0.2, None));
+    assert_eq!(ccw_angle_difference(1.0, 1.0), 0.0);
+  }
+}
+
+#[cfg(test)]
+mod angle_range_tests {
+
+use super::*;
+
+  #[test]
+  fn test_range_between() {
+    let ccw = angle_range_between(1.5 * PI, 0.5 * PI, Orientation::CounterClockwise);
+    assert!(close_equal(ccw.sweep, PI, None));
+    assert!(close_equal(angle_range_end(&ccw), 2.0 * PI + 0.5 * PI, None));
+
+    let cw = angle_range_between(0.0, 0.5 * PI, Orientation::Clockwise);
+    assert!(close_equal(cw.sweep, -1.5 * PI, None));
+
+    let empty = angle_range_between(1.0, 1.0, Orientation::Clockwise);
+    assert_eq!(empty.sweep, 0.0);
+  }
+
+  #[test]
+  fn test_range_contains() {
+    // Counter-clockwise through zero, from 315° to 45°.
+    let range = AngleRange { start: 1.75 * PI, sweep: 0.5 * PI };
+    assert!(angle_range_contains(&range, 0.0, None));
+    assert!(angle_range_contains(&range, 2.0 * PI, None));
+    assert!(angle_range_contains(&range, 0.25 * PI, None));
+    assert!(!angle_range_contains(&range, PI, None));
+    assert!(!angle_range_contains(&range, 0.3 * PI, None));
+    assert!(angle_range_contains(&range, 0.3 * PI, Some(0.1 * PI)));
+    assert!(angle_range_contains(&range, 1.7 * PI, Some(0.1 * PI)));
+
+    // Clockwise from 90° down to 0°.
+    let clockwise = AngleRange { start: 0.5 * PI, sweep: -0.5 * PI };
+    assert!(angle_range_contains(&clockwise, 0.25 * PI, None));
+    assert!(!angle_range_contains(&clockwise, PI, None));
+    assert!(close_equal(angle_range_offset(&clockwise, 0.0), 0.5 * PI, None));
+
+    let full = AngleRange { start: 0.0, sweep: -2.0 * PI };
+    assert!(angle_range_contains(&full, 1.0, Some(0.0)));
+  }
+
+  #[test]
+  fn test_arc_angle_range() {
+    let arc = Arc {
+      p1: XY { x: 0.0, y: 1.0 },
+      p2: XY { x: 1.0, y: 0.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: Some(true),
+      direction: None
+    };
+
+    let range = arc_angle_range(&arc);
+    assert!(close_equal(range.start, 0.5 * PI, None));
+    assert!(close_equal(range.sweep, 1.5 * PI, None));
+    assert!(arc_contains_angle(&arc, PI, None));
+    assert!(!arc_contains_angle(&arc, 0.25 * PI, None));
+  }
+}
+
+#[cfg(test)]
+mod unit_tests {
+
+use super::*;
+
+  #[test]
+  fn test_conversion() {
+    assert_eq!(Radians::from(Degrees(180.0)), Radians(PI));
+    assert_eq!(Degrees::from(Radians(PI / 2.0)), Degrees(90.0));
+
+    let radians: Radians = Degrees(45.0).into();
+    assert!(close_equal(radians.0, PI / 4.0, None));
+  }
+
+  #[test]
+  fn test_arithmetic_within_a_unit() {
+    assert_eq!(Degrees(30.0) + Degrees(60.0), Degrees(90.0));
+    assert_eq!(Degrees(30.0) - Degrees(60.0), Degrees(-30.0));
+    assert_eq!(-Radians(1.0), Radians(-1.0));
+    assert_eq!(Radians(1.0) * 2.0, Radians(2.0));
+    assert_eq!(Degrees(90.0) + Radians::from(Degrees(90.0)).into(), Degrees(180.0));
+    assert!(Degrees(10.0) < Degrees(20.0));
+  }
+
+  #[test]
+  fn test_ranges_accept_units() {
+    let range = angle_range_between(Degrees(90.0), Radians(PI), Orientation::CounterClockwise);
+    assert_eq!(range, angle_range_between(PI / 2.0, PI, Orientation::CounterClockwise));
+    assert!(angle_range_contains(&range, Degrees(135.0), None));
+    assert!(!angle_range_contains(&range, Degrees(45.0), None));
+    assert!(close_equal(angle_range_offset(&range, Degrees(180.0)), PI / 2.0, None));
+  }
+}
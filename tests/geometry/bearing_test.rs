@@ -0,0 +1,141 @@
+use std::f64::consts::PI;
+use rust_comp_geo::geometry::angle::bearing::{
+  angle_to_azimuth,
+  azimuth_to_angle,
+  azimuth_to_bearing,
+  bearing_to_azimuth,
+  format_bearing,
+  format_dms,
+  p2p_azimuth,
+  parse_bearing,
+  parse_dms,
+  point_at_azimuth,
+  point_at_bearing,
+  traverse_segments,
+};
+use rust_comp_geo::geometry::angle::types::{Bearing, Degrees, EastWest, NorthSouth, Radians, TraverseLeg};
+use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::types::error::GeometryError;
+use rust_comp_geo::geometry::types::types::XY;
+use rust_comp_geo::utils::utils::close_equal;
+
+#[cfg(test)]
+mod azimuth_tests {
+
+use super::*;
+
+  #[test]
+  fn test_azimuth_angle_conversion() {
+    assert!(close_equal(azimuth_to_angle(Degrees(0.0)), PI / 2.0, None));
+    assert!(close_equal(azimuth_to_angle(Degrees(90.0)), 0.0, None));
+    assert!(close_equal(azimuth_to_angle(Degrees(180.0)), 1.5 * PI, None));
+    assert!(close_equal(angle_to_azimuth(PI).0, 270.0, None));
+    assert!(close_equal(angle_to_azimuth(PI / 4.0).0, 45.0, None));
+    assert!(close_equal(angle_to_azimuth(Radians(PI / 2.0)).0, 0.0, None));
+
+    let origin = XY { x: 0.0, y: 0.0 };
+    assert!(close_equal(p2p_azimuth(origin, XY { x: -1.0, y: 1.0 }).0, 315.0, None));
+  }
+
+  #[test]
+  fn test_bearing_azimuth_conversion() {
+    let cases = [
+      (NorthSouth::North, 30.0, EastWest::East, 30.0),
+      (NorthSouth::South, 30.0, EastWest::East, 150.0),
+      (NorthSouth::South, 30.0, EastWest::West, 210.0),
+      (NorthSouth::North, 30.0, EastWest::West, 330.0),
+    ];
+    for (from, angle, towards, azimuth) in cases {
+      let bearing = Bearing { from, angle: Degrees(angle), towards };
+      assert!(close_equal(bearing_to_azimuth(&bearing).0, azimuth, None));
+
+      let back = azimuth_to_bearing(Degrees(azimuth));
+      assert_eq!((back.from, back.towards), (from, towards));
+      assert!(close_equal(back.angle.0, angle, None));
+    }
+
+    assert_eq!(azimuth_to_bearing(Degrees(-90.0)), Bearing { from: NorthSouth::North, angle: Degrees(90.0), towards: EastWest::West });
+    assert_eq!(azimuth_to_bearing(Degrees(180.0)), Bearing { from: NorthSouth::South, angle: Degrees(0.0), towards: EastWest::East });
+  }
+
+  #[test]
+  fn test_points_from_azimuth_and_bearing() {
+    let start = XY { x: 1.0, y: 1.0 };
+    assert!(points_equal(point_at_azimuth(start, Degrees(90.0), 2.0), XY { x: 3.0, y: 1.0 }, None));
+
+    let bearing = Bearing { from: NorthSouth::South, angle: Degrees(45.0), towards: EastWest::West };
+    let p = point_at_bearing(start, &bearing, 2.0_f64.sqrt());
+    assert!(points_equal(p, XY { x: 0.0, y: 0.0 }, None));
+  }
+
+  #[test]
+  fn test_traverse_segments() {
+    let legs = [
+      TraverseLeg { azimuth: Degrees(0.0), distance: 10.0 },
+      TraverseLeg { azimuth: Degrees(90.0), distance: 5.0 },
+      TraverseLeg { azimuth: bearing_to_azimuth(&parse_bearing("S0°E").unwrap()), distance: 10.0 },
+    ];
+
+    let segments = traverse_segments(XY { x: 0.0, y: 0.0 }, &legs);
+    assert_eq!(segments.len(), 3);
+    assert!(points_equal(segments[0].p2, XY { x: 0.0, y: 10.0 }, None));
+    assert_eq!(segments[1].p1, segments[0].p2);
+    assert!(points_equal(segments[2].p2, XY { x: 5.0, y: 0.0 }, None));
+    assert!(traverse_segments(XY { x: 0.0, y: 0.0 }, &[]).is_empty());
+  }
+}
+
+#[cfg(test)]
+mod dms_tests {
+
+use super::*;
+
+  #[test]
+  fn test_parse_dms() {
+    let expected = 45.0 + 30.0 / 60.0 + 15.0 / 3600.0;
+    for text in ["45°30'15\"", "45d30m15s", "45 30 15", " 45° 30′ 15″ ", "+45°30'15"] {
+      assert!(close_equal(parse_dms(text).unwrap().0, expected, None), "{}", text);
+    }
+
+    assert!(close_equal(parse_dms("-12°30'").unwrap().0, -12.5, None));
+    assert!(close_equal(parse_dms("12.25").unwrap().0, 12.25, None));
+    assert!(close_equal(parse_dms("10°7.5'").unwrap().0, 10.125, None));
+    assert!(close_equal(parse_dms("30'").unwrap().0, 0.5, None));
+  }
+
+  #[test]
+  fn test_parse_dms_rejects_bad_text() {
+    for text in ["", "abc", "45°30'15\"10", "45.5°30'", "45'30°", "45°-30'", "45°°"] {
+      assert_eq!(parse_dms(text), Err(GeometryError::UnparsableAngle), "{}", text);
+    }
+    assert_eq!(parse_dms("45°60'"), Err(GeometryError::AngleOutOfRange { angle: 60.0 }));
+  }
+
+  #[test]
+  fn test_format_dms() {
+    assert_eq!(format_dms(Degrees(45.0 + 30.0 / 60.0 + 15.0 / 3600.0), 0), "45°30'15\"");
+    assert_eq!(format_dms(Degrees(-12.5), 1), "-12°30'00.0\"");
+    assert_eq!(format_dms(Degrees(10.0 - 0.01 / 3600.0), 0), "10°00'00\"");
+    assert_eq!(format_dms(Degrees(-0.0000001), 0), "0°00'00\"");
+
+    let angle = Degrees(123.456789);
+    assert!(close_equal(parse_dms(&format_dms(angle, 4)).unwrap().0, angle.0, None));
+  }
+
+  #[test]
+  fn test_parse_and_format_bearing() {
+    let bearing = parse_bearing("N45°30'E").unwrap();
+    assert_eq!(bearing.from, NorthSouth::North);
+    assert_eq!(bearing.towards, EastWest::East);
+    assert!(close_equal(bearing.angle.0, 45.5, None));
+    assert_eq!(format_bearing(&bearing, 0), "N45°30'00\"E");
+
+    let lower = parse_bearing("s 12 30 15 w").unwrap();
+    assert_eq!((lower.from, lower.towards), (NorthSouth::South, EastWest::West));
+    assert_eq!(format_bearing(&lower, 0), "S12°30'15\"W");
+
+    assert_eq!(parse_bearing("E45°N"), Err(GeometryError::UnparsableAngle));
+    assert_eq!(parse_bearing("N-45°E"), Err(GeometryError::UnparsableAngle));
+    assert_eq!(parse_bearing("N95°E"), Err(GeometryError::AngleOutOfRange { angle: 95.0 }));
+  }
+}
@@ -0,0 +1,149 @@
+use rust_comp_geo::geometry::distance::arc::closest_point_on_arc;
+use rust_comp_geo::geometry::distance::segment::closest_point_on_segment;
+use rust_comp_geo::geometry::distance::stroke::{closest_point_on_stroke, point_stroke_distance, stroke_stroke_distance};
+use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::types::stroke_types::{AnnotatedStroke, Arc, Segment, Stroke};
+use rust_comp_geo::geometry::types::types::{Orientation, XY};
+use rust_comp_geo::utils::utils::close_equal;
+use super::helpers::segment;
+
+#[cfg(test)]
+mod closest_point_tests {
+
+use super::*;
+
+  fn upper_half_circle() -> Arc {
+    Arc {
+      p1: XY { x: 2.0, y: 0.0 },
+      p2: XY { x: -2.0, y: 0.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    }
+  }
+
+  #[test]
+  fn test_closest_point_on_segment() {
+    let segment = Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 4.0, y: 0.0 } };
+
+    let inside = closest_point_on_segment(XY { x: 1.0, y: 3.0 }, &segment);
+    assert!(points_equal(inside.point, XY { x: 1.0, y: 0.0 }, None));
+    assert!(close_equal(inside.t, 0.25, None));
+    assert!(close_equal(inside.distance, 3.0, None));
+
+    let beyond = closest_point_on_segment(XY { x: 7.0, y: 4.0 }, &segment);
+    assert!(points_equal(beyond.point, XY { x: 4.0, y: 0.0 }, None));
+    assert_eq!(beyond.t, 1.0);
+    assert!(close_equal(beyond.distance, 5.0, None));
+  }
+
+  #[test]
+  fn test_closest_point_on_arc_within_sweep() {
+    let arc = upper_half_circle();
+
+    let outside = closest_point_on_arc(XY { x: 0.0, y: 5.0 }, &arc);
+    assert!(points_equal(outside.point, XY { x: 0.0, y: 2.0 }, None));
+    assert!(close_equal(outside.t, 0.5, None));
+    assert!(close_equal(outside.distance, 3.0, None));
+
+    let inside = closest_point_on_arc(XY { x: 1.0, y: 1.0 }, &arc);
+    assert!(close_equal(inside.t, 0.25, None));
+    assert!(close_equal(inside.distance, 2.0 - 2.0_f64.sqrt(), None));
+  }
+
+  #[test]
+  fn test_closest_point_on_arc_clamped_to_endpoint() {
+    let arc = upper_half_circle();
+
+    let below = closest_point_on_arc(XY { x: 1.0, y: -1.0 }, &arc);
+    assert_eq!(below.point, XY { x: 2.0, y: 0.0 });
+    assert_eq!(below.t, 0.0);
+    assert!(close_equal(below.distance, 2.0_f64.sqrt(), None));
+
+    let center = closest_point_on_arc(XY { x: 0.0, y: 0.0 }, &arc);
+    assert!(close_equal(center.distance, 2.0, None));
+  }
+
+  #[test]
+  fn test_closest_point_on_stroke() {
+    let annotated = AnnotatedStroke {
+      stroke: Stroke::Arc(upper_half_circle()),
+      data: ()
+    };
+    let closest = closest_point_on_stroke(XY { x: -3.0, y: 0.0 }, &annotated);
+    assert!(points_equal(closest.point, XY { x: -2.0, y: 0.0 }, None));
+
+    let segment = Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 0.0, y: 4.0 } });
+    assert!(close_equal(point_stroke_distance(XY { x: -2.0, y: 2.0 }, &segment), 2.0, None));
+  }
+}
+
+#[cfg(test)]
+mod stroke_distance_tests {
+
+use super::*;
+
+  fn arc(p1: XY, p2: XY, center: XY, direction: Option<Orientation>) -> Stroke {
+    Stroke::Arc(Arc { p1, p2, center, major: None, direction })
+  }
+
+  #[test]
+  fn test_segment_segment_distance() {
+    let s1 = Stroke::Segment(segment(0.0, 0.0, 4.0, 0.0));
+    let s2 = Stroke::Segment(segment(3.0, 2.0, 6.0, 5.0));
+
+    let result = stroke_stroke_distance(&s1, &s2, None);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert!(points_equal(result.point1, XY { x: 3.0, y: 0.0 }, None));
+    assert!(points_equal(result.point2, XY { x: 3.0, y: 2.0 }, None));
+    assert!(close_equal(result.t1, 0.75, None));
+    assert_eq!(result.t2, 0.0);
+  }
+
+  #[test]
+  fn test_intersecting_strokes_are_zero_apart() {
+    let s1 = Stroke::Segment(segment(0.0, 0.0, 4.0, 4.0));
+    let s2 = Stroke::Segment(segment(0.0, 4.0, 4.0, 0.0));
+
+    let result = stroke_stroke_distance(&s1, &s2, None);
+    assert_eq!(result.distance, 0.0);
+    assert!(points_equal(result.point1, XY { x: 2.0, y: 2.0 }, None));
+    assert_eq!(result.point1, result.point2);
+  }
+
+  #[test]
+  fn test_segment_arc_interior_distance() {
+    let upper = arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, None);
+    let above = Stroke::Segment(segment(-2.0, 3.0, 2.0, 3.0));
+
+    let result = stroke_stroke_distance(&upper, &above, None);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert!(points_equal(result.point1, XY { x: 0.0, y: 1.0 }, None));
+    assert!(points_equal(result.point2, XY { x: 0.0, y: 3.0 }, None));
+    assert!(close_equal(result.t1, 0.5, None));
+    assert!(close_equal(result.t2, 0.5, None));
+  }
+
+  #[test]
+  fn test_arc_arc_distance() {
+    // Right half of a unit circle and left half of a unit circle around (4, 0).
+    let left = arc(XY { x: 0.0, y: -1.0 }, XY { x: 0.0, y: 1.0 }, XY { x: 0.0, y: 0.0 }, None);
+    let right = arc(XY { x: 4.0, y: -1.0 }, XY { x: 4.0, y: 1.0 }, XY { x: 4.0, y: 0.0 }, Some(Orientation::Clockwise));
+
+    let result = stroke_stroke_distance(&left, &right, None);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert!(points_equal(result.point1, XY { x: 1.0, y: 0.0 }, None));
+    assert!(points_equal(result.point2, XY { x: 3.0, y: 0.0 }, None));
+  }
+
+  #[test]
+  fn test_concentric_arc_distance() {
+    let inner = arc(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 1.0 }, XY { x: 0.0, y: 0.0 }, None);
+    let outer = arc(XY { x: 0.0, y: 3.0 }, XY { x: -3.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, None);
+
+    let result = stroke_stroke_distance(&inner, &outer, None);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert!(points_equal(result.point1, XY { x: 0.0, y: 1.0 }, None));
+    assert!(points_equal(result.point2, XY { x: 0.0, y: 3.0 }, None));
+  }
+}
@@ -0,0 +1,67 @@
+use rust_comp_geo::geometry::path::fit::{fit_polyline, fit_segments};
+use rust_comp_geo::geometry::path::path::flatten_path;
+use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::stroke::arc::{arc_radius, TessellateOpts};
+use rust_comp_geo::geometry::types::stroke_types::{Arc, Segment, SegmentBehavior, Stroke};
+use rust_comp_geo::geometry::types::types::XY;
+use rust_comp_geo::utils::utils::{close_equal, Tolerance};
+
+#[cfg(test)]
+mod fit_tests {
+
+use super::*;
+
+  #[test]
+  fn test_fit_polyline_merges_collinear_points() {
+    let points: Vec<XY> = (0..=10).map(|i| XY { x: i as f64, y: 0.0 }).collect();
+
+    let strokes = fit_polyline(&points, Some(0.001));
+    assert_eq!(strokes, vec![Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 10.0, y: 0.0 } })]);
+  }
+
+  #[test]
+  fn test_fit_segments_recovers_arcs_and_segments() {
+    // A quarter arc followed by a straight run, flattened into many segments.
+    let original = vec![
+      Stroke::Arc(Arc {
+        p1: XY { x: 5.0, y: 0.0 },
+        p2: XY { x: 0.0, y: 5.0 },
+        center: XY { x: 0.0, y: 0.0 },
+        major: None,
+        direction: None
+      }),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 5.0 }, p2: XY { x: -5.0, y: 5.0 } }),
+    ];
+    let mut segments = flatten_path(&original, Some(TessellateOpts { max_deviation: Some(Tolerance::absolute(0.0001)), max_angle: None }));
+    // Split the straight run as well so it must be merged back.
+    let last = segments.pop().unwrap();
+    segments.push(Segment { p1: last.p1, p2: XY { x: -2.0, y: 5.0 } });
+    segments.push(Segment { p1: XY { x: -2.0, y: 5.0 }, p2: last.p2 });
+    assert!(segments.len() > 20);
+
+    let fitted = fit_segments(&segments, Some(0.001));
+    assert_eq!(fitted.len(), 2);
+    match fitted[0] {
+      Stroke::Arc(arc) => {
+        assert!(points_equal(arc.center, XY { x: 0.0, y: 0.0 }, Some(0.001)));
+        assert!(close_equal(arc_radius(&arc), 5.0, Some(0.001)));
+      },
+      other => panic!("Expected an arc, got {:?}", other),
+    }
+    assert_eq!(fitted[1].get_p1(), XY { x: 0.0, y: 5.0 });
+    assert_eq!(fitted[1].get_p2(), XY { x: -5.0, y: 5.0 });
+  }
+
+  #[test]
+  fn test_fit_segments_keeps_corners_and_gaps() {
+    let segments = vec![
+      Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } },
+      Segment { p1: XY { x: 1.0, y: 0.0 }, p2: XY { x: 1.0, y: 1.0 } },
+      Segment { p1: XY { x: 5.0, y: 5.0 }, p2: XY { x: 6.0, y: 5.0 } },
+    ];
+
+    let fitted = fit_segments(&segments, None);
+    assert_eq!(fitted.len(), 3);
+    assert_eq!(fitted[2].get_p1(), XY { x: 5.0, y: 5.0 });
+  }
+}
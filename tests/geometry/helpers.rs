@@ -0,0 +1,22 @@
+use rust_comp_geo::geometry::types::stroke_types::{Arc, Segment};
+use rust_comp_geo::geometry::types::types::XY;
+
+pub fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Segment {
+  Segment {
+    p1: XY { x: x1, y: y1 },
+    p2: XY { x: x2, y: y2 }
+  }
+}
+
+/**
+ * The counter-clockwise quarter circle about the origin from (radius, 0) to (0, radius).
+ */
+pub fn quarter_arc(radius: f64) -> Arc {
+  Arc {
+    p1: XY { x: radius, y: 0.0 },
+    p2: XY { x: 0.0, y: radius },
+    center: XY { x: 0.0, y: 0.0 },
+    major: None,
+    direction: None
+  }
+}
@@ -0,0 +1,56 @@
+use rust_comp_geo::geometry::hull::hull::convex_hull;
+use rust_comp_geo::geometry::predicates::predicates::orient2d;
+use rust_comp_geo::geometry::types::types::XY;
+
+#[cfg(test)]
+mod convex_hull_tests {
+
+use super::*;
+
+  #[test]
+  fn test_convex_hull_of_square() {
+    let points = vec![
+      XY { x: 1.0, y: 1.0 },
+      XY { x: 0.0, y: 0.0 },
+      XY { x: 2.0, y: 2.0 },
+      XY { x: 2.0, y: 0.0 },
+      XY { x: 1.0, y: 0.0 },
+      XY { x: 0.0, y: 2.0 },
+      XY { x: 0.0, y: 0.0 },
+    ];
+
+    assert_eq!(convex_hull(&points), vec![
+      XY { x: 0.0, y: 0.0 },
+      XY { x: 2.0, y: 0.0 },
+      XY { x: 2.0, y: 2.0 },
+      XY { x: 0.0, y: 2.0 },
+    ]);
+  }
+
+  #[test]
+  fn test_convex_hull_degenerate() {
+    assert!(convex_hull(&[]).is_empty());
+
+    let collinear = vec![XY { x: 0.0, y: 0.0 }, XY { x: 2.0, y: 2.0 }, XY { x: 1.0, y: 1.0 }];
+    assert_eq!(convex_hull(&collinear), vec![XY { x: 0.0, y: 0.0 }, XY { x: 2.0, y: 2.0 }]);
+  }
+
+  #[test]
+  fn test_convex_hull_nearly_collinear() {
+    let ulp = 0.5 * f64::EPSILON;
+    let points = vec![
+      XY { x: 0.5, y: 0.5 },
+      XY { x: 12.0, y: 12.0 },
+      XY { x: 24.0, y: 24.0 },
+      XY { x: 0.5 + ulp, y: 0.5 },
+      XY { x: 0.5, y: 0.5 + 3.0 * ulp },
+    ];
+
+    let hull = convex_hull(&points);
+    for i in 0..hull.len() {
+      let (a, b, c) = (hull[i], hull[(i + 1) % hull.len()], hull[(i + 2) % hull.len()]);
+      assert!(orient2d(a, b, c) > 0.0);
+    }
+    assert!(hull.contains(&XY { x: 24.0, y: 24.0 }));
+  }
+}
@@ -0,0 +1,241 @@
+use rust_comp_geo::geometry::intersection::arc::{arc_arc_intersection, segment_arc_intersection};
+use rust_comp_geo::geometry::intersection::segment::segment_segment_intersection;
+use rust_comp_geo::geometry::intersection::stroke::stroke_intersection;
+use rust_comp_geo::geometry::intersection::types::{Intersection, IntersectionPoint};
+use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::types::stroke_types::{Arc, ArcBehavior, Segment, SegmentBehavior, Stroke};
+use rust_comp_geo::geometry::types::types::{Orientation, XY};
+use rust_comp_geo::utils::utils::{close_equal, Tolerance};
+use super::helpers::segment;
+
+#[cfg(test)]
+mod segment_segment_intersection_tests {
+
+use super::*;
+
+  fn expect_point(result: Option<Intersection>) -> IntersectionPoint {
+    match result {
+      Some(Intersection::Point(point)) => point,
+      other => panic!("Expected a point, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_crossing_segments() {
+    let s1 = segment(0.0, 0.0, 4.0, 4.0);
+    let s2 = segment(0.0, 4.0, 4.0, 0.0);
+
+    let hit = expect_point(segment_segment_intersection(&s1, &s2, None));
+    assert!(points_equal(hit.point, XY { x: 2.0, y: 2.0 }, None));
+    assert!(close_equal(hit.t1, 0.5, None));
+    assert!(close_equal(hit.t2, 0.5, None));
+  }
+
+  #[test]
+  fn test_disjoint_segments() {
+    let s1 = segment(0.0, 0.0, 1.0, 1.0);
+    let s2 = segment(3.0, 0.0, 2.0, 1.0);
+    assert_eq!(segment_segment_intersection(&s1, &s2, None), None);
+
+    let parallel = segment(0.0, 1.0, 1.0, 2.0);
+    assert_eq!(segment_segment_intersection(&s1, &parallel, None), None);
+  }
+
+  #[test]
+  fn test_touching_within_tolerance_snaps_to_endpoint() {
+    let s1 = segment(0.0, 0.0, 4.0, 0.0);
+    let s2 = segment(2.0, 0.0000001, 2.0, 3.0);
+
+    let hit = expect_point(segment_segment_intersection(&s1, &s2, None));
+    assert_eq!(hit.point, s2.get_p1());
+    assert!(close_equal(hit.t1, 0.5, None));
+    assert_eq!(hit.t2, 0.0);
+
+    assert_eq!(segment_segment_intersection(&s1, &s2, Some(0.00000001)), None);
+  }
+
+  #[test]
+  fn test_endpoint_exactly_on_nearly_degenerate_line() {
+    // q1 lies exactly on s1, but rounding in a naive cross product puts it
+    // just to the right, on the same side as q2, so the touch was missed.
+    let s1 = segment(4.6, 2.6, 9.6, 9.6);
+    let s2 = segment(7.6, 6.8, 8.6, 5.8);
+
+    let hit = expect_point(segment_segment_intersection(&s1, &s2, Some(0.0)));
+    assert_eq!(hit.point, XY { x: 7.6, y: 6.8 });
+    assert_eq!(hit.t2, 0.0);
+    assert!(close_equal(hit.t1, 0.6, None));
+
+    let away = segment(7.6 + 1e-12, 6.8, 8.6, 5.8);
+    assert!(segment_segment_intersection(&s1, &away, Some(0.0)).is_none());
+  }
+
+  #[test]
+  fn test_relative_tolerance_scales_with_coordinates() {
+    let s1 = segment(1000000.0, 0.0, 1000100.0, 0.0);
+    let s2 = segment(1000050.0, 0.0005, 1000050.0, 10.0);
+
+    assert!(segment_segment_intersection(&s1, &s2, None).is_none());
+    let hit = expect_point(segment_segment_intersection(&s1, &s2, Tolerance::new(0.000001, 0.000000001)));
+    assert_eq!(hit.t2, 0.0);
+    assert!(close_equal(hit.t1, 0.5, None));
+  }
+
+  #[test]
+  fn test_collinear_overlap() {
+    let s1 = segment(0.0, 0.0, 4.0, 0.0);
+    let s2 = segment(5.0, 0.0, 2.0, 0.0);
+
+    match segment_segment_intersection(&s1, &s2, None) {
+      Some(Intersection::Overlap { stroke, start, end }) => {
+        assert!(points_equal(stroke.get_p1(), XY { x: 2.0, y: 0.0 }, None));
+        assert!(points_equal(stroke.get_p2(), XY { x: 4.0, y: 0.0 }, None));
+        assert!(close_equal(start.t1, 0.5, None));
+        assert!(close_equal(start.t2, 1.0, None));
+        assert!(close_equal(end.t1, 1.0, None));
+        assert!(close_equal(end.t2, 1.0 / 3.0, None));
+      },
+      other => panic!("Expected an overlap, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_collinear_end_to_end_is_a_point() {
+    let s1 = segment(0.0, 0.0, 2.0, 0.0);
+    let s2 = segment(2.0, 0.0, 5.0, 0.0);
+
+    let hit = expect_point(segment_segment_intersection(&s1, &s2, None));
+    assert_eq!(hit.point, XY { x: 2.0, y: 0.0 });
+    assert_eq!(hit.t1, 1.0);
+    assert_eq!(hit.t2, 0.0);
+
+    let apart = segment(2.5, 0.0, 5.0, 0.0);
+    assert_eq!(segment_segment_intersection(&s1, &apart, None), None);
+  }
+}
+
+#[cfg(test)]
+mod arc_intersection_tests {
+
+use super::*;
+
+  fn unit_arc(p1: XY, p2: XY, center: XY, direction: Option<Orientation>) -> Arc {
+    Arc { p1, p2, center, major: None, direction }
+  }
+
+  fn points(result: &[Intersection]) -> Vec<IntersectionPoint> {
+    result.iter().map(|intersection| match intersection {
+      Intersection::Point(point) => *point,
+      other => panic!("Expected a point, got {:?}", other),
+    }).collect()
+  }
+
+  #[test]
+  fn test_segment_crossing_arc_within_sweep() {
+    // Upper half of the unit circle.
+    let arc = unit_arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, None);
+
+    // A horizontal line crosses the circle twice, both within the sweep.
+    let segment = Segment { p1: XY { x: -2.0, y: 0.5 }, p2: XY { x: 2.0, y: 0.5 } };
+    let hits = points(&segment_arc_intersection(&segment, &arc, None));
+    assert_eq!(hits.len(), 2);
+    let x = 0.75_f64.sqrt();
+    assert!(points_equal(hits[0].point, XY { x: -x, y: 0.5 }, None));
+    assert!(points_equal(hits[1].point, XY { x, y: 0.5 }, None));
+    assert!(hits[0].t1 < hits[1].t1);
+    assert!(close_equal(hits[1].t2, 1.0 / 6.0, None));
+
+    // A vertical line crosses the circle twice, but only once within the sweep.
+    let segment = Segment { p1: XY { x: 0.0, y: -2.0 }, p2: XY { x: 0.0, y: 2.0 } };
+    let hits = points(&segment_arc_intersection(&segment, &arc, None));
+    assert_eq!(hits.len(), 1);
+    assert!(points_equal(hits[0].point, XY { x: 0.0, y: 1.0 }, None));
+    assert!(close_equal(hits[0].t2, 0.5, None));
+  }
+
+  #[test]
+  fn test_segment_tangent_to_arc() {
+    let arc = unit_arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, None);
+    let segment = Segment { p1: XY { x: -2.0, y: 1.0 }, p2: XY { x: 2.0, y: 1.0 } };
+
+    let hits = points(&segment_arc_intersection(&segment, &arc, None));
+    assert_eq!(hits.len(), 1);
+    assert!(points_equal(hits[0].point, XY { x: 0.0, y: 1.0 }, None));
+    assert!(close_equal(hits[0].t1, 0.5, None));
+  }
+
+  #[test]
+  fn test_arc_arc_crossing_respects_sweep() {
+    let a1 = unit_arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, None);
+    // Circle of radius 1 around (1, 0), lower half only.
+    let a2 = unit_arc(XY { x: 2.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, XY { x: 1.0, y: 0.0 }, Some(Orientation::Clockwise));
+    assert_eq!(arc_arc_intersection(&a1, &a2, None).len(), 0);
+
+    // Upper half of the same circle meets the first arc once.
+    let a3 = unit_arc(XY { x: 2.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, XY { x: 1.0, y: 0.0 }, None);
+    let hits = points(&arc_arc_intersection(&a1, &a3, None));
+    assert_eq!(hits.len(), 1);
+    assert!(points_equal(hits[0].point, XY { x: 0.5, y: 0.75_f64.sqrt() }, None));
+    assert!(close_equal(hits[0].t1, 1.0 / 3.0, None));
+    assert!(close_equal(hits[0].t2, 2.0 / 3.0, None));
+  }
+
+  #[test]
+  fn test_arc_arc_tangent() {
+    let a1 = unit_arc(XY { x: 0.0, y: -1.0 }, XY { x: 0.0, y: 1.0 }, XY { x: 0.0, y: 0.0 }, None);
+    let a2 = unit_arc(XY { x: 2.0, y: 1.0 }, XY { x: 2.0, y: -1.0 }, XY { x: 2.0, y: 0.0 }, None);
+
+    let hits = points(&arc_arc_intersection(&a1, &a2, None));
+    assert_eq!(hits.len(), 1);
+    assert!(points_equal(hits[0].point, XY { x: 1.0, y: 0.0 }, None));
+    assert!(close_equal(hits[0].t1, 0.5, None));
+    assert!(close_equal(hits[0].t2, 0.5, None));
+  }
+
+  #[test]
+  fn test_cocircular_arc_overlap() {
+    let center = XY { x: 0.0, y: 0.0 };
+    // Upper half, counter-clockwise.
+    let a1 = unit_arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, center, None);
+    // Quarter from 90° to 180° traversed clockwise.
+    let a2 = unit_arc(XY { x: -1.0, y: 0.0 }, XY { x: 0.0, y: 1.0 }, center, Some(Orientation::Clockwise));
+
+    let result = arc_arc_intersection(&a1, &a2, None);
+    assert_eq!(result.len(), 1);
+    match result[0] {
+      Intersection::Overlap { stroke, start, end } => {
+        assert!(points_equal(stroke.get_p1(), XY { x: 0.0, y: 1.0 }, None));
+        assert_eq!(stroke.get_p2(), XY { x: -1.0, y: 0.0 });
+        assert_eq!(stroke.get_direction(), Some(Orientation::CounterClockwise));
+        assert!(close_equal(start.t1, 0.5, None));
+        assert!(close_equal(start.t2, 1.0, None));
+        assert!(close_equal(end.t1, 1.0, None));
+        assert!(close_equal(end.t2, 0.0, None));
+      },
+      other => panic!("Expected an overlap, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_cocircular_arcs_touching_at_both_ends() {
+    let center = XY { x: 0.0, y: 0.0 };
+    let upper = unit_arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, center, None);
+    let lower = unit_arc(XY { x: -1.0, y: 0.0 }, XY { x: 1.0, y: 0.0 }, center, None);
+
+    let hits = points(&arc_arc_intersection(&upper, &lower, None));
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].point, XY { x: 1.0, y: 0.0 });
+    assert_eq!(hits[1].point, XY { x: -1.0, y: 0.0 });
+  }
+
+  #[test]
+  fn test_stroke_intersection_dispatch() {
+    let arc = Stroke::Arc(unit_arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0, y: 0.0 }, None));
+    let segment = Stroke::Segment(Segment { p1: XY { x: 0.0, y: -2.0 }, p2: XY { x: 0.0, y: 2.0 } });
+
+    let hits = points(&stroke_intersection(&arc, &segment, None));
+    assert_eq!(hits.len(), 1);
+    assert!(close_equal(hits[0].t1, 0.5, None));
+    assert!(close_equal(hits[0].t2, 0.75, None));
+  }
+}
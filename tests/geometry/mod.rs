@@ -1,4 +1,15 @@
+mod helpers;
 mod point_test;
 mod types_test;
 mod stroke_test;
-mod path_test;
\ No newline at end of file
+mod path_test;
+mod intersection_test;
+mod distance_test;
+mod fit_test;
+mod transform_test;
+mod vector_test;
+mod space_test;
+mod angle_test;
+mod bearing_test;
+mod predicates_test;
+mod hull_test;
\ No newline at end of file
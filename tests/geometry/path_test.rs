@@ -1,9 +1,55 @@
 use rust_comp_geo::geometry::point::point::points_equal;
-use rust_comp_geo::geometry::path::path::{UnscramblePathOpts, unscramble_path};
+use rust_comp_geo::geometry::path::path::{
+  UnscramblePathOpts,
+  flatten_annotated_path,
+  flatten_path,
+  path_bounding_box,
+  path_area,
+  path_closest_point,
+  path_contains_point,
+  path_continuity,
+  path_distance_of_point,
+  path_length,
+  path_orientation,
+  path_point_at_distance,
+  path_position_at_distance,
+  path_signed_area,
+  path_tangent_at_distance,
+  path_winding_number,
+  tessellate_path,
+  unscramble_path,
+  unscramble_paths
+};
+use rust_comp_geo::utils::utils::close_equal;
+use std::f64::consts::PI;
+use rust_comp_geo::geometry::path::types::{Containment, FillRule, Path};
+use rust_comp_geo::geometry::stroke::stroke::split_stroke;
+use rust_comp_geo::geometry::types::types::Orientation;
+use rust_comp_geo::geometry::types::error::GeometryError;
+use rust_comp_geo::geometry::transform::transform::{Transform2D, Transformable};
+use rust_comp_geo::utils::utils::Tolerance;
+use rust_comp_geo::geometry::stroke::arc::TessellateOpts;
 use rust_comp_geo::geometry::stroke::stroke::{stroke_with_new_data, reverse_stroke};
 use rust_comp_geo::geometry::types::types:: XY;
 use rust_comp_geo::geometry::types::stroke_types::{ Stroke, Segment, Arc, AnnotatedStroke, SegmentBehavior };
 
+fn closed(strokes: Vec<Stroke>) -> Path<Stroke> {
+  Path::try_new(strokes, None).unwrap()
+}
+
+fn half_circles(center: XY, radius: f64, direction: Orientation, turns: usize) -> Path<Stroke> {
+  let right = XY { x: center.x + radius, y: center.y };
+  let left = XY { x: center.x - radius, y: center.y };
+  let (p1, p2) = match direction {
+    Orientation::CounterClockwise => (right, left),
+    Orientation::Clockwise => (left, right),
+  };
+  closed((0..turns).flat_map(|_| [
+    Stroke::Arc(Arc { p1, p2, center, major: None, direction: Some(direction) }),
+    Stroke::Arc(Arc { p1: p2, p2: p1, center, major: None, direction: Some(direction) }),
+  ]).collect())
+}
+
 #[cfg(test)]
 mod unscramble_path_tests {
 
@@ -33,6 +79,7 @@ use super::*;
         p1: XY { x: 10.0, y: 2.0 },
         p2: XY { x: 7.0, y: 5.0 },
         major: None,
+        direction: None,
       }),
     ];
 
@@ -77,6 +124,7 @@ use super::*;
         p1: XY { x: 7.0, y: 5.0 },
         p2: XY { x: 10.0, y: 2.0 },
         major: None,
+        direction: None,
       }),
     ];
 
@@ -134,6 +182,7 @@ use super::*;
           p1: XY { x: 10.0, y: 2.0 },
           p2: XY { x: 7.0, y: 5.0 },
           major: None,
+          direction: None,
         }),
         data: "right".to_string(),
       },
@@ -197,10 +246,572 @@ use super::*;
         p1: XY { x: 7.0, y: 5.0 },
         p2: XY { x: 10.0, y: 2.0 },
         major: Some(false),
+        direction: None,
       }),
     ];
 
     let unscrambled = unscramble_path(path, None);
     assert_eq!(unscrambled.len(), 2);
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn test_unscramble_path_with_tolerance() {
+    let path: Vec<Stroke> = vec![
+      Stroke::Segment(Segment {
+        p1: XY { x: 1000000.0, y: 0.0 },
+        p2: XY { x: 1000100.0, y: 0.0 },
+      }),
+      Stroke::Segment(Segment {
+        p1: XY { x: 1000100.05, y: 0.0 },
+        p2: XY { x: 1000100.0, y: 100.0 },
+      }),
+    ];
+
+    assert_eq!(unscramble_path(path.clone(), None).len(), 2);
+
+    let unscrambled = unscramble_path(path, Some(
+      UnscramblePathOpts {
+        tolerance: Some(Tolerance::absolute(0.1)),
+        reverse: None,
+      }
+    ));
+    assert_eq!(unscrambled.len(), 1);
+    assert_eq!(unscrambled[0].len(), 2);
+  }
+
+  #[test]
+  fn test_unscramble_path_with_relative_tolerance() {
+    // Survey coordinates thousands of kilometres from the origin, with
+    // 5mm gaps between the stroke ends.
+    let path: Vec<Stroke> = vec![
+      Stroke::Segment(Segment { p1: XY { x: 500000.0, y: 4000000.0 }, p2: XY { x: 500100.0, y: 4000000.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 500200.0, y: 4000000.005 }, p2: XY { x: 500100.0, y: 4000000.005 } }),
+      Stroke::Segment(Segment { p1: XY { x: 500200.005, y: 4000000.0 }, p2: XY { x: 500200.0, y: 4000100.0 } }),
+    ];
+
+    assert_eq!(unscramble_path(path.clone(), None).len(), 3);
+
+    let opts = UnscramblePathOpts { tolerance: Some(Tolerance::new(0.0, 0.000000005)), reverse: None };
+    let unscrambled = unscramble_path(path.clone(), Some(opts));
+    assert_eq!(unscrambled.len(), 1);
+    assert_eq!(unscrambled[0].len(), 3);
+    assert_eq!(unscrambled[0][0].get_p1(), XY { x: 500000.0, y: 4000000.0 });
+    assert_eq!(unscrambled[0][2].get_p2(), XY { x: 500200.0, y: 4000100.0 });
+
+    let opts = UnscramblePathOpts { tolerance: Some(Tolerance::new(0.0, 0.000000005)), reverse: None };
+    assert_eq!(unscramble_paths(path, Some(opts)).len(), 1);
+  }
+}
+
+#[cfg(test)]
+mod path_bounding_box_tests {
+
+use super::*;
+
+  #[test]
+  fn test_path_bounding_box_includes_arc_bulge() {
+    let path: Vec<Stroke> = vec![
+      Stroke::Segment(Segment {
+        p1: XY { x: 0.0, y: 0.0 },
+        p2: XY { x: 4.0, y: 0.0 },
+      }),
+      Stroke::Arc(Arc {
+        center: XY { x: 2.0, y: 0.0 },
+        p1: XY { x: 4.0, y: 0.0 },
+        p2: XY { x: 0.0, y: 0.0 },
+        major: None,
+        direction: None,
+      }),
+    ];
+
+    let bb = path_bounding_box(&path);
+    assert_eq!(bb.x_min, 0.0);
+    assert_eq!(bb.x_max, 4.0);
+    assert_eq!(bb.y_min, 0.0);
+    assert_eq!(bb.y_max, 2.0);
+  }
+}
+
+#[cfg(test)]
+mod tessellate_path_tests {
+
+use super::*;
+
+  #[test]
+  fn test_tessellate_path_splits_at_gaps() {
+    let path: Vec<Stroke> = vec![
+      Stroke::Segment(Segment {
+        p1: XY { x: 0.0, y: 0.0 },
+        p2: XY { x: 4.0, y: 0.0 },
+      }),
+      Stroke::Arc(Arc {
+        center: XY { x: 2.0, y: 0.0 },
+        p1: XY { x: 4.0, y: 0.0 },
+        p2: XY { x: 0.0, y: 0.0 },
+        major: None,
+        direction: None,
+      }),
+      Stroke::Segment(Segment {
+        p1: XY { x: 10.0, y: 0.0 },
+        p2: XY { x: 11.0, y: 0.0 },
+      }),
+    ];
+    let opts = Some(TessellateOpts { max_deviation: None, max_angle: Some(std::f64::consts::PI / 4.0) });
+
+    let polylines = tessellate_path(&path, opts);
+    assert_eq!(polylines.len(), 2);
+    assert_eq!(polylines[0].len(), 6);
+    assert_eq!(polylines[0][0], XY { x: 0.0, y: 0.0 });
+    assert_eq!(polylines[0][5], XY { x: 0.0, y: 0.0 });
+    assert_eq!(polylines[1], vec![XY { x: 10.0, y: 0.0 }, XY { x: 11.0, y: 0.0 }]);
+
+    assert_eq!(flatten_path(&path, opts).len(), 6);
+  }
+
+  #[test]
+  fn test_flatten_annotated_path() {
+    let path = vec![
+      AnnotatedStroke {
+        stroke: Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 4.0, y: 0.0 } }),
+        data: "line",
+      },
+      AnnotatedStroke {
+        stroke: Stroke::Arc(Arc {
+          center: XY { x: 2.0, y: 0.0 },
+          p1: XY { x: 4.0, y: 0.0 },
+          p2: XY { x: 0.0, y: 0.0 },
+          major: None,
+          direction: None,
+        }),
+        data: "arc",
+      },
+    ];
+    let opts = Some(TessellateOpts { max_deviation: None, max_angle: Some(PI / 4.0) });
+
+    let pieces = flatten_annotated_path(&path, opts);
+    assert_eq!(pieces.len(), 5);
+    assert_eq!(pieces[0].data, "line");
+    assert!(pieces[1..].iter().all(|piece| piece.data == "arc" && matches!(piece.stroke, Stroke::Segment(_))));
+    assert_eq!(pieces[4].get_p2(), XY { x: 0.0, y: 0.0 });
+  }
+}
+
+#[cfg(test)]
+mod path_type_tests {
+
+use super::*;
+
+  fn square(gap: f64) -> Vec<Stroke> {
+    vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 1.0, y: 0.0 }, p2: XY { x: 1.0, y: 1.0 } }),
+      Stroke::Arc(Arc {
+        p1: XY { x: 1.0, y: 1.0 },
+        p2: XY { x: 0.0, y: 1.0 },
+        center: XY { x: 0.5, y: 1.0 },
+        major: None,
+        direction: None,
+      }),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 1.0 }, p2: XY { x: 0.0, y: gap } }),
+    ]
+  }
+
+  #[test]
+  fn test_closed_path() {
+    let path = Path::try_new(square(0.0), None).unwrap();
+    assert!(path.is_closed());
+    assert_eq!(path.len(), 4);
+    assert!(!path.is_empty());
+    assert_eq!(path.iter().count(), 4);
+    assert_eq!((&path).into_iter().next(), path.strokes().first());
+
+    let vertices: Vec<XY> = path.vertices().collect();
+    assert_eq!(vertices, vec![
+      XY { x: 0.0, y: 0.0 },
+      XY { x: 1.0, y: 0.0 },
+      XY { x: 1.0, y: 1.0 },
+      XY { x: 0.0, y: 1.0 },
+    ]);
+    assert_eq!(path.into_strokes().len(), 4);
+  }
+
+  #[test]
+  fn test_open_path() {
+    let mut strokes = square(0.0);
+    strokes.pop();
+    let path = Path::try_new(strokes, None).unwrap();
+    assert!(!path.is_closed());
+    assert_eq!(path.vertices().count(), 4);
+    assert_eq!(path.vertices().last(), Some(XY { x: 0.0, y: 1.0 }));
+
+    let empty: Path<Stroke> = Path::try_new(vec![], None).unwrap();
+    assert!(empty.is_empty());
+    assert!(!empty.is_closed());
+    assert_eq!(empty.vertices().count(), 0);
+  }
+
+  #[test]
+  fn test_closing_uses_tolerance() {
+    assert!(!Path::try_new(square(0.01), None).unwrap().is_closed());
+    assert!(Path::try_new(square(0.01), Some(0.1)).unwrap().is_closed());
+  }
+
+  #[test]
+  fn test_relative_tolerance_scales_with_path() {
+    let far: Vec<Stroke> = square(0.0005).iter()
+      .map(|stroke| stroke.transform(&Transform2D::translation(1000000.0, 0.0)).unwrap())
+      .collect();
+    let tolerance = Tolerance::new(0.000001, 0.000000001);
+
+    assert!(!Path::try_new(far.clone(), None).unwrap().is_closed());
+    assert!(Path::try_new(far.clone(), tolerance).unwrap().is_closed());
+    assert!(path_continuity(&far, tolerance).is_ok());
+
+    // Unscrambling with the same absolute tolerance agrees on closing.
+    let absolute = Tolerance::absolute(0.001);
+    let paths = unscramble_paths(far.clone(), Some(UnscramblePathOpts { tolerance: Some(absolute), reverse: None }));
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].is_closed(), Path::try_new(far, absolute).unwrap().is_closed());
+  }
+
+  #[test]
+  fn test_discontinuous_path() {
+    let mut strokes = square(0.0);
+    strokes.swap(1, 3);
+
+    assert!(matches!(path_continuity(&strokes, None), Err(GeometryError::DiscontinuousPath { index: 1, .. })));
+    assert_eq!(Path::try_new(strokes, None).unwrap_err(), GeometryError::DiscontinuousPath { index: 1, gap: 2.0_f64.sqrt() });
+  }
+
+  #[test]
+  fn test_unscramble_paths() {
+    let mut strokes = square(0.0);
+    strokes.swap(0, 2);
+    strokes.push(Stroke::Segment(Segment { p1: XY { x: 5.0, y: 5.0 }, p2: XY { x: 6.0, y: 5.0 } }));
+
+    let paths = unscramble_paths(strokes, None);
+    assert_eq!(paths.len(), 2);
+    assert!(paths[0].is_closed());
+    assert_eq!(paths[0].len(), 4);
+    assert!(path_continuity(paths[0].strokes(), Some(0.001)).is_ok());
+    assert!(!paths[1].is_closed());
+  }
+}
+
+#[cfg(test)]
+mod path_distance_tests {
+
+use super::*;
+
+  /**
+   * A 2 by 1 slot: two straight sides joined by a half circle at the right
+   * end, optionally closed by a straight left end.
+   */
+  fn slot(closed: bool) -> Path<Stroke> {
+    let mut strokes = vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.0 } }),
+      Stroke::Arc(Arc {
+        p1: XY { x: 2.0, y: 0.0 },
+        p2: XY { x: 2.0, y: 1.0 },
+        center: XY { x: 2.0, y: 0.5 },
+        major: None,
+        direction: None,
+      }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 1.0 }, p2: XY { x: 0.0, y: 1.0 } }),
+    ];
+    if closed {
+      strokes.push(Stroke::Segment(Segment { p1: XY { x: 0.0, y: 1.0 }, p2: XY { x: 0.0, y: 0.0 } }));
+    }
+    Path::try_new(strokes, None).unwrap()
+  }
+
+  #[test]
+  fn test_path_length() {
+    assert!(close_equal(path_length(&slot(false)), 4.0 + PI / 2.0, None));
+    assert!(close_equal(path_length(&slot(true)), 5.0 + PI / 2.0, None));
+  }
+
+  #[test]
+  fn test_point_at_distance_on_open_path() {
+    let path = slot(false);
+
+    assert_eq!(path_point_at_distance(&path, 1.0), Some(XY { x: 1.0, y: 0.0 }));
+    let on_arc = path_point_at_distance(&path, 2.0 + PI / 4.0).unwrap();
+    assert!(points_equal(on_arc, XY { x: 2.5, y: 0.5 }, None));
+    assert!(points_equal(path_point_at_distance(&path, 3.0 + PI / 2.0).unwrap(), XY { x: 1.0, y: 1.0 }, None));
+
+    // Open paths clamp to their ends.
+    assert_eq!(path_point_at_distance(&path, -1.0), Some(XY { x: 0.0, y: 0.0 }));
+    assert_eq!(path_point_at_distance(&path, 100.0), Some(XY { x: 0.0, y: 1.0 }));
+  }
+
+  #[test]
+  fn test_point_at_distance_on_closed_path_wraps() {
+    let path = slot(true);
+    let length = path_length(&path);
+
+    assert!(points_equal(path_point_at_distance(&path, length + 1.0).unwrap(), XY { x: 1.0, y: 0.0 }, None));
+    assert!(points_equal(path_point_at_distance(&path, -0.5).unwrap(), XY { x: 0.0, y: 0.5 }, None));
+    assert_eq!(path_position_at_distance(&path, length).unwrap().index, 0);
+  }
+
+  #[test]
+  fn test_position_and_tangent_at_joints() {
+    let path = slot(false);
+
+    let joint = path_position_at_distance(&path, 2.0).unwrap();
+    assert_eq!((joint.index, joint.t, joint.along), (1, 0.0, 2.0));
+
+    // The half circle leaves the corner heading the same way as the first side.
+    let tangent = path_tangent_at_distance(&path, 2.0).unwrap();
+    assert!(close_equal(tangent.i, 1.0, None) && close_equal(tangent.j, 0.0, None));
+    let tangent = path_tangent_at_distance(&path, 3.0 + PI / 2.0).unwrap();
+    assert!(close_equal(tangent.i, -1.0, None) && close_equal(tangent.j, 0.0, None));
+
+    let end = path_position_at_distance(&path, 100.0).unwrap();
+    assert_eq!((end.index, end.t), (2, 1.0));
+
+    let empty: Path<Stroke> = Path::try_new(vec![], None).unwrap();
+    assert_eq!(path_point_at_distance(&empty, 1.0), None);
+    assert_eq!(path_tangent_at_distance(&empty, 1.0), None);
+  }
+
+  #[test]
+  fn test_distance_of_point() {
+    let path = slot(true);
+
+    let closest = path_closest_point(&path, XY { x: 3.0, y: 0.5 }).unwrap();
+    assert!(points_equal(closest.point, XY { x: 2.5, y: 0.5 }, None));
+    assert_eq!(closest.position.index, 1);
+    assert!(close_equal(closest.distance, 0.5, None));
+    assert!(close_equal(closest.position.along, 2.0 + PI / 4.0, None));
+
+    assert!(close_equal(path_distance_of_point(&path, XY { x: -1.0, y: 0.25 }).unwrap(), 4.75 + PI / 2.0, None));
+    assert!(close_equal(path_distance_of_point(&path, XY { x: 1.0, y: 0.9 }).unwrap(), 3.0 + PI / 2.0, None));
+
+    // Distances along the path round trip through points on it.
+    for distance in [0.5, 2.3, 3.9, 5.5] {
+      let point = path_point_at_distance(&path, distance).unwrap();
+      assert!(close_equal(path_distance_of_point(&path, point).unwrap(), distance, None));
+    }
+
+    let empty: Path<Stroke> = Path::try_new(vec![], None).unwrap();
+    assert_eq!(path_distance_of_point(&empty, XY { x: 0.0, y: 0.0 }), None);
+  }
+}
+
+#[cfg(test)]
+mod path_area_tests {
+
+use super::*;
+
+  fn reversed(path: &Path<Stroke>) -> Path<Stroke> {
+    closed(path.iter().rev().map(reverse_stroke).collect())
+  }
+
+  #[test]
+  fn test_polygon_area() {
+    let square = closed(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 0.0 }, p2: XY { x: 2.0, y: 3.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 3.0 }, p2: XY { x: 0.0, y: 3.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 3.0 }, p2: XY { x: 0.0, y: 0.0 } }),
+    ]);
+
+    assert_eq!(path_signed_area(&square), Ok(6.0));
+    assert_eq!(path_signed_area(&reversed(&square)), Ok(-6.0));
+    assert_eq!(path_area(&reversed(&square)), Ok(6.0));
+    assert_eq!(path_orientation(&square), Ok(Some(Orientation::CounterClockwise)));
+    assert_eq!(path_orientation(&reversed(&square)), Ok(Some(Orientation::Clockwise)));
+  }
+
+  #[test]
+  fn test_circle_area() {
+    let path = half_circles(XY { x: 3.0, y: -2.0 }, 2.0, Orientation::CounterClockwise, 1);
+    assert!(close_equal(path_signed_area(&path).unwrap(), 4.0 * PI, None));
+    assert!(close_equal(path_signed_area(&reversed(&path)).unwrap(), -4.0 * PI, None));
+
+    // Splitting arcs into smaller pieces does not change the area.
+    let pieces = closed(path.iter().flat_map(|stroke| split_stroke(stroke, &[0.2, 0.7])).collect());
+    assert!(close_equal(path_signed_area(&pieces).unwrap(), 4.0 * PI, None));
+
+    let far = half_circles(XY { x: 1000000.0, y: 1000000.0 }, 0.5, Orientation::CounterClockwise, 1);
+    assert!(close_equal(path_area(&far).unwrap(), PI / 4.0, None));
+  }
+
+  #[test]
+  fn test_arcs_bulging_in_and_out() {
+    // A 2 by 2 square whose right side bulges out by a half circle of
+    // radius 1/2 and whose left side is notched in by one of radius 1.
+    let path = closed(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.5 } }),
+      Stroke::Arc(Arc {
+        p1: XY { x: 2.0, y: 0.5 },
+        p2: XY { x: 2.0, y: 1.5 },
+        center: XY { x: 2.0, y: 1.0 },
+        major: None,
+        direction: Some(Orientation::CounterClockwise),
+      }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 1.5 }, p2: XY { x: 2.0, y: 2.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 2.0 }, p2: XY { x: 0.0, y: 2.0 } }),
+      Stroke::Arc(Arc {
+        p1: XY { x: 0.0, y: 2.0 },
+        p2: XY { x: 0.0, y: 0.0 },
+        center: XY { x: 0.0, y: 1.0 },
+        major: None,
+        direction: Some(Orientation::Clockwise),
+      }),
+    ]);
+
+    // The bulge adds π/8, and the notch of radius 1 removes π/2.
+    assert!(close_equal(path_signed_area(&path).unwrap(), 4.0 + PI / 8.0 - PI / 2.0, None));
+  }
+
+  #[test]
+  fn test_open_and_empty_paths() {
+    let open = Path::try_new(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 1.0, y: 0.0 }, p2: XY { x: 1.0, y: 1.0 } }),
+    ], None).unwrap();
+    assert_eq!(path_signed_area(&open), Err(GeometryError::OpenPath));
+    assert_eq!(path_orientation(&open), Err(GeometryError::OpenPath));
+
+    let there_and_back = closed(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 1.0, y: 0.0 }, p2: XY { x: 0.0, y: 0.0 } }),
+    ]);
+    assert_eq!(path_orientation(&there_and_back), Ok(None));
+  }
+}
+
+#[cfg(test)]
+mod path_containment_tests {
+
+use super::*;
+
+  // A 2 by 2 square whose right side bulges out and whose left side is
+  // notched in by half circles of radius 1/2.
+  fn bulge_and_notch() -> Path<Stroke> {
+    let arc = |p1: XY, p2: XY, center: XY, direction: Orientation| {
+      Stroke::Arc(Arc { p1, p2, center, major: None, direction: Some(direction) })
+    };
+    closed(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.5 } }),
+      arc(XY { x: 2.0, y: 0.5 }, XY { x: 2.0, y: 1.5 }, XY { x: 2.0, y: 1.0 }, Orientation::CounterClockwise),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 1.5 }, p2: XY { x: 2.0, y: 2.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 2.0 }, p2: XY { x: 0.0, y: 2.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 2.0 }, p2: XY { x: 0.0, y: 1.5 } }),
+      arc(XY { x: 0.0, y: 1.5 }, XY { x: 0.0, y: 0.5 }, XY { x: 0.0, y: 1.0 }, Orientation::Clockwise),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.5 }, p2: XY { x: 0.0, y: 0.0 } }),
+    ])
+  }
+
+  fn contains(path: &Path<Stroke>, point: XY) -> Containment {
+    path_contains_point(path, point, FillRule::NonZero, None).unwrap()
+  }
+
+  #[test]
+  fn test_polygon() {
+    let square = closed(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 1.0, y: 0.0 }, p2: XY { x: 1.0, y: 1.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 1.0, y: 1.0 }, p2: XY { x: 0.0, y: 1.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 1.0 }, p2: XY { x: 0.0, y: 0.0 } }),
+    ]);
+
+    assert_eq!(path_winding_number(&square, XY { x: 0.5, y: 0.5 }), Ok(1));
+    assert_eq!(path_winding_number(&square, XY { x: 1.5, y: 0.5 }), Ok(0));
+    assert_eq!(contains(&square, XY { x: 0.5, y: 0.5 }), Containment::Inside);
+    assert_eq!(contains(&square, XY { x: -0.5, y: 0.5 }), Containment::Outside);
+    // Level with a vertex, where the ray passes through two strokes at once.
+    assert_eq!(contains(&square, XY { x: -0.5, y: 1.0 }), Containment::Outside);
+    assert_eq!(contains(&square, XY { x: 0.5, y: 1.0 }), Containment::Boundary);
+    assert_eq!(contains(&square, XY { x: 1.0, y: 1.0 }), Containment::Boundary);
+    assert_eq!(contains(&square, XY { x: 1.0005, y: 0.5 }), Containment::Outside);
+    assert_eq!(
+      path_contains_point(&square, XY { x: 1.0005, y: 0.5 }, FillRule::NonZero, Some(0.001)),
+      Ok(Containment::Boundary)
+    );
+
+    let reversed = closed(square.iter().rev().map(reverse_stroke).collect());
+    assert_eq!(path_winding_number(&reversed, XY { x: 0.5, y: 0.5 }), Ok(-1));
+    assert_eq!(contains(&reversed, XY { x: 0.5, y: 0.5 }), Containment::Inside);
+  }
+
+  #[test]
+  fn test_circle() {
+    let center = XY { x: 3.0, y: -2.0 };
+    for direction in [Orientation::CounterClockwise, Orientation::Clockwise] {
+      let circle = half_circles(center, 2.0, direction, 1);
+      // The center and the point beside it lie on the chords of both arcs.
+      assert_eq!(contains(&circle, center), Containment::Inside);
+      assert_eq!(contains(&circle, XY { x: 4.0, y: -2.0 }), Containment::Inside);
+      assert_eq!(contains(&circle, XY { x: 4.0, y: -0.5 }), Containment::Inside);
+      assert_eq!(contains(&circle, XY { x: 4.5, y: -0.5 }), Containment::Outside);
+      assert_eq!(contains(&circle, XY { x: 6.0, y: -2.0 }), Containment::Outside);
+      assert_eq!(contains(&circle, XY { x: 3.0, y: 0.0 }), Containment::Boundary);
+      assert_eq!(contains(&circle, XY { x: 3.0 + 2.0 * (PI / 3.0).cos(), y: -2.0 + 2.0 * (PI / 3.0).sin() }), Containment::Boundary);
+    }
+
+    let far = half_circles(XY { x: 1000000.0, y: 1000000.0 }, 0.5, Orientation::CounterClockwise, 1);
+    assert_eq!(contains(&far, XY { x: 1000000.25, y: 1000000.25 }), Containment::Inside);
+    assert_eq!(contains(&far, XY { x: 1000000.5, y: 1000000.5 }), Containment::Outside);
+  }
+
+  #[test]
+  fn test_bulge_and_notch() {
+    let path = bulge_and_notch();
+
+    assert_eq!(contains(&path, XY { x: 1.0, y: 1.0 }), Containment::Inside);
+    // Inside the bulge, past the square.
+    assert_eq!(contains(&path, XY { x: 2.3, y: 1.0 }), Containment::Inside);
+    assert_eq!(contains(&path, XY { x: 2.3, y: 0.5 }), Containment::Outside);
+    // Inside the notch, within the square.
+    assert_eq!(contains(&path, XY { x: 0.3, y: 1.0 }), Containment::Outside);
+    assert_eq!(contains(&path, XY { x: 0.3, y: 0.3 }), Containment::Inside);
+    // On the chords of the arcs, which are not part of the path.
+    assert_eq!(contains(&path, XY { x: 2.0, y: 1.0 }), Containment::Inside);
+    assert_eq!(contains(&path, XY { x: 0.0, y: 1.0 }), Containment::Outside);
+    assert_eq!(contains(&path, XY { x: 2.5, y: 1.0 }), Containment::Boundary);
+    assert_eq!(contains(&path, XY { x: 0.5, y: 1.0 }), Containment::Boundary);
+  }
+
+  #[test]
+  fn test_fill_rules() {
+    let twice = half_circles(XY { x: 0.0, y: 0.0 }, 1.0, Orientation::CounterClockwise, 2);
+    let inside = XY { x: 0.2, y: 0.1 };
+    assert_eq!(path_winding_number(&twice, inside), Ok(2));
+    assert_eq!(path_contains_point(&twice, inside, FillRule::NonZero, None), Ok(Containment::Inside));
+    assert_eq!(path_contains_point(&twice, inside, FillRule::EvenOdd, None), Ok(Containment::Outside));
+
+    // A figure of eight winds around its two loops in opposite directions.
+    let eight = closed(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 2.0, y: 2.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 2.0 }, p2: XY { x: 2.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 2.0, y: 0.0 }, p2: XY { x: 0.0, y: 2.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 2.0 }, p2: XY { x: 0.0, y: 0.0 } }),
+    ]);
+    assert_eq!(path_winding_number(&eight, XY { x: 0.3, y: 1.0 }), Ok(1));
+    assert_eq!(path_winding_number(&eight, XY { x: 1.7, y: 1.0 }), Ok(-1));
+    for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+      assert_eq!(path_contains_point(&eight, XY { x: 0.3, y: 1.0 }, fill_rule, None), Ok(Containment::Inside));
+      assert_eq!(path_contains_point(&eight, XY { x: 1.0, y: 1.5 }, fill_rule, None), Ok(Containment::Outside));
+      assert_eq!(path_contains_point(&eight, XY { x: 1.0, y: 1.0 }, fill_rule, None), Ok(Containment::Boundary));
+    }
+  }
+
+  #[test]
+  fn test_open_path() {
+    let open = Path::try_new(vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      Stroke::Segment(Segment { p1: XY { x: 1.0, y: 0.0 }, p2: XY { x: 1.0, y: 1.0 } }),
+    ], None).unwrap();
+    assert_eq!(path_winding_number(&open, XY { x: 0.8, y: 0.2 }), Err(GeometryError::OpenPath));
+    assert_eq!(
+      path_contains_point(&open, XY { x: 0.8, y: 0.2 }, FillRule::EvenOdd, None),
+      Err(GeometryError::OpenPath)
+    );
+  }
+}
@@ -8,7 +8,7 @@ use rust_comp_geo::geometry::point::point::{
   translate_point
 };
 use rust_comp_geo::geometry::types::types::{ BoundingBox, XY };
-use rust_comp_geo::utils::utils::close_equal;
+use rust_comp_geo::utils::utils::{close_equal, Tolerance};
 use std::f64::consts::PI;
 
 #[cfg(test)] // Only compiles when running tests
@@ -81,6 +81,14 @@ mod p2p_angle_tests {
     }
   }
 
+  #[test]
+  fn p2p_angle_due_west() {
+    // A negative zero rise makes atan2 return -π, which must come out as π.
+    let p1 = XY { x: 0.0, y: 0.0 };
+    assert_eq!(p2p_angle(p1, XY { x: -1.0, y: -0.0 }), PI);
+    assert_eq!(p2p_angle(p1, XY { x: -1.0, y: 0.0 }), PI);
+  }
+
 }
 
 #[cfg(test)]
@@ -104,8 +112,19 @@ mod points_equal_tests {
     for (p2, expected) in vect {
       assert_eq!(point_equals(p1)(p2), expected);
       assert_eq!(points_equal(p1, p2, None), expected);
+      assert_eq!(points_equal(p1, p2, Tolerance::default()), expected);
     }
   }
+
+  #[test]
+  fn test_points_equal_relative_tolerance() {
+    let tolerance = Tolerance::new(0.000001, 0.000000001);
+    let far = XY { x: 2000000.0, y: 10.0 };
+
+    assert!(points_equal(far, XY { x: 2000000.001, y: 10.001 }, tolerance));
+    assert!(!points_equal(far, XY { x: 2000000.001, y: 10.001 }, None));
+    assert!(!points_equal(XY { x: 2.0, y: 10.0 }, XY { x: 2.001, y: 10.001 }, tolerance));
+  }
 }
 
 #[cfg(test)]
@@ -0,0 +1,84 @@
+use rust_comp_geo::geometry::predicates::predicates::{incircle, orient2d, orientation};
+use rust_comp_geo::geometry::types::types::{Orientation, XY};
+
+fn sign(x: f64) -> i32 {
+  if x > 0.0 { 1 } else if x < 0.0 { -1 } else { 0 }
+}
+
+#[cfg(test)]
+mod orient2d_tests {
+
+use super::*;
+
+  #[test]
+  fn test_orientation_of_simple_triangles() {
+    let a = XY { x: 0.0, y: 0.0 };
+    let b = XY { x: 1.0, y: 0.0 };
+    let c = XY { x: 0.0, y: 1.0 };
+
+    assert_eq!(orient2d(a, b, c), 1.0);
+    assert_eq!(orient2d(a, c, b), -1.0);
+    assert_eq!(orientation(a, b, c), Some(Orientation::CounterClockwise));
+    assert_eq!(orientation(b, a, c), Some(Orientation::Clockwise));
+    assert_eq!(orientation(a, b, XY { x: 2.0, y: 0.0 }), None);
+  }
+
+  #[test]
+  fn test_nearly_collinear_signs_are_exact() {
+    // Points a few ulps either side of the line y = x, where the naive
+    // determinant rounds to the wrong sign or to zero.
+    let ulp = 0.5 * f64::EPSILON;
+    let b = XY { x: 12.0, y: 12.0 };
+    let c = XY { x: 24.0, y: 24.0 };
+
+    for i in 0..16_i32 {
+      for j in 0..16_i32 {
+        let a = XY { x: 0.5 + i as f64 * ulp, y: 0.5 + j as f64 * ulp };
+        let expected = (j - i).signum();
+        assert_eq!(sign(orient2d(a, b, c)), expected, "{} {}", i, j);
+        // Cyclic permutations agree and swaps flip the sign.
+        assert_eq!(sign(orient2d(b, c, a)), expected);
+        assert_eq!(sign(orient2d(b, a, c)), -expected);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod incircle_tests {
+
+use super::*;
+
+  #[test]
+  fn test_incircle_simple() {
+    let a = XY { x: 0.0, y: 0.0 };
+    let b = XY { x: 1.0, y: 0.0 };
+    let c = XY { x: 0.0, y: 1.0 };
+
+    assert!(incircle(a, b, c, XY { x: 0.5, y: 0.5 }) > 0.0);
+    assert!(incircle(a, b, c, XY { x: 2.0, y: 2.0 }) < 0.0);
+    assert!(incircle(a, c, b, XY { x: 0.5, y: 0.5 }) < 0.0);
+    assert_eq!(incircle(a, b, c, XY { x: 1.0, y: 1.0 }), 0.0);
+  }
+
+  #[test]
+  fn test_nearly_cocircular_signs_are_exact() {
+    // The circle through a, b and c has center (0.5, 0.5), and (1, 1) lies on it.
+    // Moving the last point by single ulps crosses the circle.
+    let a = XY { x: 0.0, y: 0.0 };
+    let b = XY { x: 1.0, y: 0.0 };
+    let c = XY { x: 0.0, y: 1.0 };
+    let ulp = f64::EPSILON;
+
+    assert!(incircle(a, b, c, XY { x: 1.0, y: 1.0 - 0.5 * ulp }) > 0.0);
+    assert!(incircle(a, b, c, XY { x: 1.0, y: 1.0 + ulp }) < 0.0);
+    assert!(incircle(a, b, c, XY { x: 1.0 - 0.5 * ulp, y: 1.0 + ulp }) < 0.0);
+
+    // Large offsets make the naive lifted terms lose all of their precision.
+    let shift = 1e8;
+    let moved = |p: XY| XY { x: p.x + shift, y: p.y + shift };
+    assert_eq!(incircle(moved(a), moved(b), moved(c), moved(XY { x: 1.0, y: 1.0 })), 0.0);
+    assert!(incircle(moved(a), moved(b), moved(c), XY { x: shift + 1.0, y: shift + 1.0 - 2.0 * ulp * shift }) > 0.0);
+    assert!(incircle(moved(a), moved(b), moved(c), XY { x: shift + 1.0, y: shift + 1.0 + 2.0 * ulp * shift }) < 0.0);
+  }
+}
@@ -0,0 +1,181 @@
+use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::space::plane::{
+  line_plane_intersection,
+  plane_frame,
+  plane_from_normal,
+  plane_from_points,
+  point_to_local,
+  point_to_world,
+  project_point_to_plane,
+  segment_plane_intersection,
+  signed_distance_to_plane,
+};
+use rust_comp_geo::geometry::space::point::{p2p_dist_3d, points_equal_3d};
+use rust_comp_geo::geometry::space::segment::{closest_point_on_segment_3d, segment_3d_length, segment_segment_distance_3d};
+use rust_comp_geo::geometry::space::types::{Plane, Segment3D};
+use rust_comp_geo::geometry::types::error::GeometryError;
+use rust_comp_geo::geometry::types::types::{Vector3D, XY, XYZ};
+use rust_comp_geo::utils::utils::close_equal;
+
+fn xyz(x: f64, y: f64, z: f64) -> XYZ {
+  XYZ { x, y, z }
+}
+
+#[cfg(test)]
+mod segment_3d_tests {
+
+use super::*;
+
+  #[test]
+  fn test_distance_and_length() {
+    assert_eq!(p2p_dist_3d(xyz(1.0, 2.0, 3.0), xyz(3.0, 5.0, 9.0)), 7.0);
+    assert_eq!(segment_3d_length(&Segment3D { p1: xyz(0.0, 0.0, 0.0), p2: xyz(0.0, 0.0, 2.0) }), 2.0);
+  }
+
+  #[test]
+  fn test_closest_point_on_segment() {
+    let segment = Segment3D { p1: xyz(0.0, 0.0, 0.0), p2: xyz(4.0, 0.0, 0.0) };
+
+    let inside = closest_point_on_segment_3d(xyz(1.0, 3.0, 4.0), &segment);
+    assert_eq!(inside.point, xyz(1.0, 0.0, 0.0));
+    assert_eq!(inside.t, 0.25);
+    assert_eq!(inside.distance, 5.0);
+
+    let past_end = closest_point_on_segment_3d(xyz(6.0, 0.0, 1.0), &segment);
+    assert_eq!(past_end.point, segment.p2);
+    assert_eq!(past_end.t, 1.0);
+  }
+
+  #[test]
+  fn test_skew_segments() {
+    let s1 = Segment3D { p1: xyz(-1.0, 0.0, 0.0), p2: xyz(1.0, 0.0, 0.0) };
+    let s2 = Segment3D { p1: xyz(0.0, -1.0, 2.0), p2: xyz(0.0, 1.0, 2.0) };
+
+    let result = segment_segment_distance_3d(&s1, &s2);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert!(points_equal_3d(result.point1, xyz(0.0, 0.0, 0.0), None));
+    assert!(points_equal_3d(result.point2, xyz(0.0, 0.0, 2.0), None));
+    assert!(close_equal(result.t1, 0.5, None));
+    assert!(close_equal(result.t2, 0.5, None));
+  }
+
+  #[test]
+  fn test_clamped_and_parallel_segments() {
+    let s1 = Segment3D { p1: xyz(0.0, 0.0, 0.0), p2: xyz(1.0, 0.0, 0.0) };
+    let beyond = Segment3D { p1: xyz(3.0, -1.0, 0.0), p2: xyz(3.0, 1.0, 0.0) };
+    let result = segment_segment_distance_3d(&s1, &beyond);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert_eq!(result.t1, 1.0);
+
+    let parallel = Segment3D { p1: xyz(0.5, 0.0, 1.0), p2: xyz(2.0, 0.0, 1.0) };
+    assert!(close_equal(segment_segment_distance_3d(&s1, &parallel).distance, 1.0, None));
+
+    let point = Segment3D { p1: xyz(0.5, 2.0, 0.0), p2: xyz(0.5, 2.0, 0.0) };
+    let result = segment_segment_distance_3d(&s1, &point);
+    assert!(close_equal(result.distance, 2.0, None));
+    assert!(close_equal(result.t1, 0.5, None));
+  }
+}
+
+#[cfg(test)]
+mod plane_tests {
+
+use super::*;
+
+  fn raised_plane() -> Plane {
+    plane_from_normal(xyz(0.0, 0.0, 2.0), Vector3D::new(0.0, 0.0, 5.0)).unwrap()
+  }
+
+  #[test]
+  fn test_plane_constructors() {
+    assert_eq!(raised_plane().normal, Vector3D::new(0.0, 0.0, 1.0));
+    assert_eq!(plane_from_normal(xyz(0.0, 0.0, 0.0), Vector3D::zero()), Err(GeometryError::DegenerateDirection));
+
+    let plane = plane_from_points(xyz(0.0, 0.0, 1.0), xyz(1.0, 0.0, 1.0), xyz(0.0, 1.0, 1.0)).unwrap();
+    assert_eq!(plane.normal, Vector3D::new(0.0, 0.0, 1.0));
+
+    let flipped = plane_from_points(xyz(0.0, 0.0, 1.0), xyz(0.0, 1.0, 1.0), xyz(1.0, 0.0, 1.0)).unwrap();
+    assert_eq!(flipped.normal, Vector3D::new(0.0, 0.0, -1.0));
+
+    assert_eq!(
+      plane_from_points(xyz(0.0, 0.0, 0.0), xyz(1.0, 1.0, 1.0), xyz(2.0, 2.0, 2.0)),
+      Err(GeometryError::CollinearPoints)
+    );
+    assert_eq!(
+      plane_from_points(xyz(0.0, 0.0, 0.0), xyz(0.0, 0.0, 0.0), xyz(2.0, 2.0, 2.0)),
+      Err(GeometryError::CoincidentPoints)
+    );
+  }
+
+  #[test]
+  fn test_point_plane_distance_and_projection() {
+    let plane = raised_plane();
+
+    assert_eq!(signed_distance_to_plane(xyz(3.0, 4.0, 5.0), &plane), 3.0);
+    assert_eq!(signed_distance_to_plane(xyz(3.0, 4.0, 0.0), &plane), -2.0);
+    assert_eq!(project_point_to_plane(xyz(3.0, 4.0, 5.0), &plane), xyz(3.0, 4.0, 2.0));
+  }
+
+  #[test]
+  fn test_line_plane_intersection() {
+    let plane = raised_plane();
+
+    let hit = line_plane_intersection(xyz(0.0, 0.0, 0.0), Vector3D::new(1.0, 0.0, 1.0), &plane, None).unwrap();
+    assert!(points_equal_3d(hit, xyz(2.0, 0.0, 2.0), None));
+
+    assert_eq!(line_plane_intersection(xyz(0.0, 0.0, 0.0), Vector3D::new(1.0, 1.0, 0.0), &plane, None), None);
+    assert_eq!(line_plane_intersection(xyz(0.0, 0.0, 0.0), Vector3D::zero(), &plane, None), None);
+  }
+
+  #[test]
+  fn test_segment_plane_intersection() {
+    let plane = raised_plane();
+
+    let crossing = Segment3D { p1: xyz(0.0, 0.0, 0.0), p2: xyz(0.0, 4.0, 4.0) };
+    let (point, t) = segment_plane_intersection(&crossing, &plane, None).unwrap();
+    assert!(points_equal_3d(point, xyz(0.0, 2.0, 2.0), None));
+    assert!(close_equal(t, 0.5, None));
+
+    let touching = Segment3D { p1: xyz(1.0, 1.0, 2.0), p2: xyz(1.0, 1.0, 6.0) };
+    assert_eq!(segment_plane_intersection(&touching, &plane, None), Some((touching.p1, 0.0)));
+
+    let short = Segment3D { p1: xyz(0.0, 0.0, 0.0), p2: xyz(0.0, 0.0, 1.0) };
+    assert_eq!(segment_plane_intersection(&short, &plane, None), None);
+
+    let lying = Segment3D { p1: xyz(0.0, 0.0, 2.0), p2: xyz(1.0, 0.0, 2.0) };
+    assert_eq!(segment_plane_intersection(&lying, &plane, None), None);
+  }
+
+  #[test]
+  fn test_plane_frame() {
+    let frame = plane_frame(&raised_plane(), None).unwrap();
+    assert_eq!(frame.x_axis, Vector3D::new(1.0, 0.0, 0.0));
+    assert_eq!(frame.y_axis, Vector3D::new(0.0, 1.0, 0.0));
+
+    let tilted = plane_from_normal(xyz(1.0, 1.0, 1.0), Vector3D::new(1.0, 1.0, 1.0)).unwrap();
+    let frame = plane_frame(&tilted, Some(Vector3D::new(1.0, 0.0, 0.0))).unwrap();
+    assert!(close_equal(frame.x_axis.dot(frame.normal), 0.0, None));
+    assert!(close_equal(frame.x_axis.length(), 1.0, None));
+    assert!(close_equal(frame.x_axis.cross(frame.y_axis).dot(frame.normal), 1.0, None));
+
+    assert_eq!(
+      plane_frame(&raised_plane(), Some(Vector3D::new(0.0, 0.0, 1.0))),
+      Err(GeometryError::DegenerateDirection)
+    );
+  }
+
+  #[test]
+  fn test_local_round_trip() {
+    let tilted = plane_from_points(xyz(1.0, 0.0, 0.0), xyz(0.0, 1.0, 0.0), xyz(0.0, 0.0, 1.0)).unwrap();
+    let frame = plane_frame(&tilted, None).unwrap();
+
+    let local = XY { x: 0.5, y: -2.0 };
+    let world = point_to_world(local, &frame);
+    assert!(close_equal(signed_distance_to_plane(world, &tilted), 0.0, None));
+    assert!(points_equal(point_to_local(world, &frame), local, None));
+
+    let above = world + frame.normal * 3.0;
+    assert!(points_equal(point_to_local(above, &frame), local, None));
+    assert!(points_equal_3d(point_to_world(point_to_local(above, &frame), &frame), world, None));
+  }
+}
@@ -1,9 +1,49 @@
-use rust_comp_geo::geometry::stroke::segment::reverse_segment;
-use rust_comp_geo::geometry::stroke::arc::reverse_arc;
-use rust_comp_geo::geometry::stroke::stroke::reverse_stroke;
-use rust_comp_geo::geometry::types::types::XY;
-use rust_comp_geo::geometry::types::stroke_types::{ Segment, Stroke, Arc, SegmentBehavior, ArcBehavior };
+use rust_comp_geo::geometry::stroke::segment::{reverse_segment, segment_length};
+use rust_comp_geo::geometry::stroke::arc::{
+  arc_bounding_box,
+  arc_bulge,
+  arc_center_angles,
+  arc_extreme_points,
+  arc_direction,
+  arc_from_bulge,
+  arc_from_center,
+  arc_from_center_angles,
+  arc_from_radius,
+  arc_from_three_points,
+  arc_length,
+  arc_radius,
+  arc_signed_sweep_angle,
+  arc_sweep_angle,
+  reverse_arc,
+  arc_tessellation_count,
+  tessellate_arc,
+  TessellateOpts,
+  MAX_TESSELLATION_COUNT
+};
+use rust_comp_geo::geometry::stroke::stroke::{
+  normalize_stroke,
+  offset_stroke,
+  flatten_annotated_stroke,
+  flatten_stroke,
+  reverse_stroke,
+  split_stroke,
+  split_stroke_at_points,
+  stroke_bounding_box,
+  stroke_length,
+  stroke_normal_at,
+  stroke_point_at,
+  stroke_point_at_distance,
+  stroke_tangent_at,
+  validate_stroke,
+};
+use rust_comp_geo::geometry::types::types::{Orientation, XY};
+use rust_comp_geo::geometry::angle::types::Degrees;
+use rust_comp_geo::geometry::types::stroke_types::{ AnnotatedStroke, Segment, Stroke, Arc, SegmentBehavior, ArcBehavior };
 use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::types::error::GeometryError;
+use rust_comp_geo::utils::utils::{close_equal, Tolerance};
+use std::f64::consts::PI;
+use super::helpers::quarter_arc;
 
 #[cfg(test)]
 mod segment_tests {
@@ -40,7 +80,8 @@ use super::*;
       p1: XY { x: 0.0, y: 0.0 },
       p2: XY { x: 1.0, y: 1.0 },
       center: XY { x: 0.5, y: 0.5 },
-      major: Some(true)
+      major: Some(true),
+      direction: None
     };
 
     let reversed = reverse_arc(&arc);
@@ -57,7 +98,8 @@ use super::*;
       p1: XY { x: 0.0, y: 0.0 },
       p2: XY { x: 1.0, y: 1.0 },
       center: XY { x: 0.5, y: 0.5 },
-      major: Some(true)
+      major: Some(true),
+      direction: None
     });
 
     let reversed: Stroke = reverse_stroke(&arc);
@@ -66,4 +108,616 @@ use super::*;
     assert!(points_equal(arc.get_center().unwrap(), reversed.get_center().unwrap(), None));
     assert_eq!(arc.get_major(), reversed.get_major());
   }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod length_tests {
+
+use super::*;
+
+  #[test]
+  fn test_segment_length() {
+    let segment = Segment {
+      p1: XY { x: 0.0, y: 0.0 },
+      p2: XY { x: 3.0, y: 4.0 }
+    };
+
+    assert!(close_equal(segment_length(&segment), 5.0, None));
+    assert!(close_equal(stroke_length(&Stroke::Segment(segment)), 5.0, None));
+  }
+
+  #[test]
+  fn test_minor_and_major_arc_sweep() {
+    let minor = quarter_arc(1.0);
+    assert_eq!(arc_direction(&minor), Orientation::CounterClockwise);
+    assert!(close_equal(arc_sweep_angle(&minor), 0.5 * PI, None));
+    assert!(close_equal(arc_length(&minor), 0.5 * PI, None));
+
+    let major = Arc { major: Some(true), ..quarter_arc(1.0) };
+    assert_eq!(arc_direction(&major), Orientation::Clockwise);
+    assert!(close_equal(arc_sweep_angle(&major), 1.5 * PI, None));
+    assert!(close_equal(arc_signed_sweep_angle(&major), -1.5 * PI, None));
+    assert!(close_equal(arc_length(&major), 1.5 * PI, None));
+  }
+
+  #[test]
+  fn test_explicit_direction_overrides_major() {
+    let arc = Arc {
+      direction: Some(Orientation::Clockwise),
+      ..quarter_arc(1.0)
+    };
+
+    assert_eq!(arc_direction(&arc), Orientation::Clockwise);
+    assert!(close_equal(arc_sweep_angle(&arc), 1.5 * PI, None));
+  }
+
+  #[test]
+  fn test_half_circle_direction() {
+    let arc = Arc {
+      p1: XY { x: 2.0, y: 0.0 },
+      p2: XY { x: -2.0, y: 0.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    };
+
+    assert_eq!(arc_direction(&arc), Orientation::CounterClockwise);
+    assert!(close_equal(arc_length(&arc), 2.0 * PI, None));
+
+    // Reversing pins the direction so the same half circle is traced back.
+    let reversed = reverse_arc(&arc);
+    assert_eq!(reversed.get_direction(), Some(Orientation::Clockwise));
+    assert!(close_equal(arc_signed_sweep_angle(&reversed), -PI, None));
+  }
+
+  #[test]
+  fn test_reverse_arc_flips_explicit_direction() {
+    let arc = Arc {
+      direction: Some(Orientation::CounterClockwise),
+      ..quarter_arc(1.0)
+    };
+
+    let reversed = reverse_arc(&arc);
+    assert_eq!(reversed.get_direction(), Some(Orientation::Clockwise));
+    assert!(close_equal(arc_sweep_angle(&reversed), arc_sweep_angle(&arc), None));
+  }
+
+  #[test]
+  fn test_stroke_length_of_annotated_arc() {
+    let annotated = AnnotatedStroke {
+      stroke: Stroke::Arc(Arc {
+        p1: XY { x: 3.0, y: 0.0 },
+        p2: XY { x: 0.0, y: 3.0 },
+        center: XY { x: 0.0, y: 0.0 },
+        major: None,
+        direction: None
+      }),
+      data: "label"
+    };
+
+    assert!(close_equal(stroke_length(&annotated), 1.5 * PI, None));
+  }
+}
+
+#[cfg(test)]
+mod evaluation_tests {
+
+use super::*;
+
+  #[test]
+  fn test_segment_evaluation() {
+    let segment = Stroke::Segment(Segment {
+      p1: XY { x: 0.0, y: 0.0 },
+      p2: XY { x: 4.0, y: 0.0 }
+    });
+
+    assert!(points_equal(stroke_point_at(&segment, 0.25), XY { x: 1.0, y: 0.0 }, None));
+    assert!(points_equal(stroke_point_at_distance(&segment, 3.0), XY { x: 3.0, y: 0.0 }, None));
+    // Parameters outside the stroke are clamped to its endpoints.
+    assert!(points_equal(stroke_point_at(&segment, 2.0), XY { x: 4.0, y: 0.0 }, None));
+
+    let tangent = stroke_tangent_at(&segment, 0.5);
+    assert!(close_equal(tangent.i, 1.0, None) && close_equal(tangent.j, 0.0, None));
+    let normal = stroke_normal_at(&segment, 0.5);
+    assert!(close_equal(normal.i, 0.0, None) && close_equal(normal.j, 1.0, None));
+  }
+
+  #[test]
+  fn test_counter_clockwise_arc_evaluation() {
+    let arc = Stroke::Arc(Arc {
+      p1: XY { x: 1.0, y: 0.0 },
+      p2: XY { x: -1.0, y: 0.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    });
+
+    assert!(points_equal(stroke_point_at(&arc, 0.5), XY { x: 0.0, y: 1.0 }, None));
+    assert!(points_equal(stroke_point_at_distance(&arc, 0.25 * PI), XY { x: 0.5_f64.sqrt(), y: 0.5_f64.sqrt() }, None));
+
+    let tangent = stroke_tangent_at(&arc, 0.5);
+    assert!(close_equal(tangent.i, -1.0, None) && close_equal(tangent.j, 0.0, None));
+    let normal = stroke_normal_at(&arc, 0.5);
+    assert!(close_equal(normal.i, 0.0, None) && close_equal(normal.j, -1.0, None));
+  }
+
+  #[test]
+  fn test_clockwise_arc_evaluation() {
+    let arc = Stroke::Arc(Arc {
+      p1: XY { x: 1.0, y: 0.0 },
+      p2: XY { x: -1.0, y: 0.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: Some(Orientation::Clockwise)
+    });
+
+    assert!(points_equal(stroke_point_at(&arc, 0.5), XY { x: 0.0, y: -1.0 }, None));
+
+    let tangent = stroke_tangent_at(&arc, 0.0);
+    assert!(close_equal(tangent.i, 0.0, None) && close_equal(tangent.j, -1.0, None));
+    let normal = stroke_normal_at(&arc, 0.0);
+    assert!(close_equal(normal.i, 1.0, None) && close_equal(normal.j, 0.0, None));
+  }
+}
+
+#[cfg(test)]
+mod bounding_box_tests {
+
+use super::*;
+
+  #[test]
+  fn test_segment_bounding_box() {
+    let segment = Stroke::Segment(Segment {
+      p1: XY { x: 4.0, y: -1.0 },
+      p2: XY { x: 0.0, y: 3.0 }
+    });
+
+    let bb = stroke_bounding_box(&segment);
+    assert_eq!((bb.x_min, bb.x_max, bb.y_min, bb.y_max), (0.0, 4.0, -1.0, 3.0));
+  }
+
+  #[test]
+  fn test_minor_arc_bounding_box_includes_bulge() {
+    // Quarter arc from 45° to 135° bulges up through the top of the circle.
+    let r = 2.0_f64.sqrt();
+    let arc = Stroke::Arc(Arc {
+      p1: XY { x: 1.0, y: 1.0 },
+      p2: XY { x: -1.0, y: 1.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    });
+
+    let bb = stroke_bounding_box(&arc);
+    assert!(close_equal(bb.x_min, -1.0, None));
+    assert!(close_equal(bb.x_max, 1.0, None));
+    assert!(close_equal(bb.y_min, 1.0, None));
+    assert!(close_equal(bb.y_max, r, None));
+  }
+
+  #[test]
+  fn test_major_arc_bounding_box() {
+    let r = 2.0_f64.sqrt();
+    let arc = AnnotatedStroke {
+      stroke: Stroke::Arc(Arc {
+        p1: XY { x: 1.0, y: 1.0 },
+        p2: XY { x: -1.0, y: 1.0 },
+        center: XY { x: 0.0, y: 0.0 },
+        major: Some(true),
+        direction: None
+      }),
+      data: 0
+    };
+
+    let bb = stroke_bounding_box(&arc);
+    assert!(close_equal(bb.x_min, -r, None));
+    assert!(close_equal(bb.x_max, r, None));
+    assert!(close_equal(bb.y_min, -r, None));
+    assert!(close_equal(bb.y_max, 1.0, None));
+  }
+
+  #[test]
+  fn test_arc_ending_just_short_of_an_axis() {
+    let arc = Arc { p2: XY { x: 1e-12, y: 10.0 }, ..quarter_arc(10.0) };
+    let points = arc_extreme_points(&arc);
+    assert!(points.contains(&XY { x: 0.0, y: 10.0 }));
+    assert_eq!(arc_bounding_box(&arc).x_min, 0.0);
+  }
+}
+
+#[cfg(test)]
+mod split_tests {
+
+use super::*;
+
+  #[test]
+  fn test_split_segment_at_params() {
+    let segment = Stroke::Segment(Segment {
+      p1: XY { x: 0.0, y: 0.0 },
+      p2: XY { x: 4.0, y: 0.0 }
+    });
+
+    // Unsorted, repeated and out of range parameters are tidied up.
+    let pieces = split_stroke(&segment, &[0.75, 0.25, 0.25, 0.0, 1.5]);
+    assert_eq!(pieces.len(), 3);
+    assert_eq!(pieces[0].get_p1(), XY { x: 0.0, y: 0.0 });
+    assert!(points_equal(pieces[0].get_p2(), XY { x: 1.0, y: 0.0 }, None));
+    assert_eq!(pieces[0].get_p2(), pieces[1].get_p1());
+    assert!(points_equal(pieces[1].get_p2(), XY { x: 3.0, y: 0.0 }, None));
+    assert_eq!(pieces[2].get_p2(), XY { x: 4.0, y: 0.0 });
+  }
+
+  #[test]
+  fn test_split_arc_sets_major_and_center() {
+    // Three quarters of the unit circle, from 0° clockwise round to 90°.
+    let arc = Stroke::Arc(Arc {
+      p1: XY { x: 1.0, y: 0.0 },
+      p2: XY { x: 0.0, y: 1.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: Some(true),
+      direction: None
+    });
+
+    let pieces = split_stroke(&arc, &[1.0 / 3.0]);
+    assert_eq!(pieces.len(), 2);
+    assert!(points_equal(pieces[0].get_p2(), XY { x: 0.0, y: -1.0 }, None));
+    assert_eq!(pieces[0].get_major(), Some(false));
+    assert!(close_equal(arc_sweep_angle(&pieces[0]), 0.5 * PI, None));
+    assert_eq!(pieces[0].get_direction(), None);
+
+    // The remaining half circle must keep turning clockwise.
+    assert_eq!(pieces[1].get_center(), Some(XY { x: 0.0, y: 0.0 }));
+    assert_eq!(pieces[1].get_direction(), Some(Orientation::Clockwise));
+    assert!(close_equal(arc_signed_sweep_angle(&pieces[1]), -PI, None));
+    assert!(close_equal(stroke_length(&pieces[0]) + stroke_length(&pieces[1]), stroke_length(&arc), None));
+  }
+
+  #[test]
+  fn test_split_annotated_stroke_at_points_keeps_data() {
+    let annotated = AnnotatedStroke {
+      stroke: Stroke::Segment(Segment {
+        p1: XY { x: 0.0, y: 0.0 },
+        p2: XY { x: 0.0, y: 6.0 }
+      }),
+      data: "wall"
+    };
+
+    let points = vec![
+      XY { x: 0.0000001, y: 4.0 },
+      XY { x: 0.0, y: 2.0 },
+      // Off the stroke.
+      XY { x: 1.0, y: 3.0 },
+      // Coincides with an end.
+      XY { x: 0.0, y: 6.0 },
+    ];
+    let pieces = split_stroke_at_points(&annotated, &points, None);
+    assert_eq!(pieces.len(), 3);
+    assert!(pieces.iter().all(|piece| piece.data == "wall"));
+    assert_eq!(pieces[0].get_p2(), XY { x: 0.0, y: 2.0 });
+    // Points just off the stroke are snapped onto it.
+    assert_eq!(pieces[1].get_p2(), XY { x: 0.0, y: 4.0 });
+    assert_eq!(pieces[2].get_p1(), XY { x: 0.0, y: 4.0 });
+  }
+
+  #[test]
+  fn test_split_arc_at_points_off_the_circle() {
+    let arc = Stroke::Arc(quarter_arc(10.0));
+    let near = XY { x: 50.0_f64.sqrt() + 0.0001, y: 50.0_f64.sqrt() };
+    let pieces = split_stroke_at_points(&arc, &[near], Some(0.001));
+
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces[0].get_p2(), pieces[1].get_p1());
+    assert!(close_equal(arc_radius(&pieces[0]), 10.0, None));
+    let end = pieces[0].get_p2();
+    assert!(close_equal((end.x * end.x + end.y * end.y).sqrt(), 10.0, None));
+  }
+}
+
+#[cfg(test)]
+mod offset_tests {
+
+use super::*;
+
+  #[test]
+  fn test_offset_segment_to_the_left() {
+    let segment = Stroke::Segment(Segment {
+      p1: XY { x: 0.0, y: 0.0 },
+      p2: XY { x: 4.0, y: 0.0 }
+    });
+
+    let left = offset_stroke(&segment, 2.0).unwrap();
+    assert!(points_equal(left.get_p1(), XY { x: 0.0, y: 2.0 }, None));
+    assert!(points_equal(left.get_p2(), XY { x: 4.0, y: 2.0 }, None));
+
+    let right = offset_stroke(&segment, -1.0).unwrap();
+    assert!(points_equal(right.get_p1(), XY { x: 0.0, y: -1.0 }, None));
+  }
+
+  #[test]
+  fn test_offset_arc_changes_radius() {
+    let ccw = Stroke::Arc(Arc {
+      p1: XY { x: 2.0, y: 0.0 },
+      p2: XY { x: 0.0, y: 2.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    });
+
+    // Left of a counter-clockwise arc is towards the center.
+    let inner = offset_stroke(&ccw, 0.5).unwrap();
+    assert!(points_equal(inner.get_p1(), XY { x: 1.5, y: 0.0 }, None));
+    assert!(points_equal(inner.get_p2(), XY { x: 0.0, y: 1.5 }, None));
+    assert_eq!(inner.get_center(), ccw.get_center());
+
+    let outer = offset_stroke(&reverse_stroke(&ccw), 0.5).unwrap();
+    assert!(points_equal(outer.get_p1(), XY { x: 0.0, y: 2.5 }, None));
+    assert!(points_equal(outer.get_p2(), XY { x: 2.5, y: 0.0 }, None));
+  }
+
+  #[test]
+  fn test_offset_arc_collapse_is_an_error() {
+    let arc = Stroke::Arc(Arc {
+      p1: XY { x: 2.0, y: 0.0 },
+      p2: XY { x: 0.0, y: 2.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    });
+
+    assert_eq!(offset_stroke(&arc, 2.0), Err(GeometryError::CollapsedArc { radius: 2.0, offset: 2.0 }));
+    assert!(offset_stroke(&arc, 3.0).is_err());
+    assert!(offset_stroke(&arc, -3.0).is_ok());
+  }
+}
+
+#[cfg(test)]
+mod arc_constructor_tests {
+
+use super::*;
+
+  #[test]
+  fn test_arc_from_three_points() {
+    let arc = arc_from_three_points(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 1.0 }, XY { x: -1.0, y: 0.0 }).unwrap();
+    assert!(points_equal(arc.center, XY { x: 0.0, y: 0.0 }, None));
+    assert_eq!(arc.direction, Some(Orientation::CounterClockwise));
+    assert_eq!(arc.major, Some(false));
+
+    // Passing below the chord goes the long way round, clockwise.
+    let arc = arc_from_three_points(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: -1.0 }, XY { x: 0.0, y: 1.0 }).unwrap();
+    assert_eq!(arc.direction, Some(Orientation::Clockwise));
+    assert_eq!(arc.major, Some(true));
+    assert!(close_equal(arc_sweep_angle(&arc), 1.5 * PI, None));
+
+    let collinear = arc_from_three_points(XY { x: 0.0, y: 0.0 }, XY { x: 1.0, y: 1.0 }, XY { x: 2.0, y: 2.0 });
+    assert_eq!(collinear, Err(GeometryError::CollinearPoints));
+  }
+
+  #[test]
+  fn test_arc_bulge_round_trip() {
+    let p1 = XY { x: 0.0, y: 0.0 };
+    let p2 = XY { x: 2.0, y: 0.0 };
+
+    // A bulge of one is a counter-clockwise half circle, bulging to the right.
+    let half = arc_from_bulge(p1, p2, 1.0).unwrap();
+    assert!(points_equal(half.center, XY { x: 1.0, y: 0.0 }, None));
+    assert!(points_equal(stroke_point_at(&Stroke::Arc(half), 0.5), XY { x: 1.0, y: -1.0 }, None));
+    assert!(close_equal(arc_bulge(&half), 1.0, None));
+
+    let bulge = -(PI / 8.0).tan();
+    let quarter = arc_from_bulge(p1, p2, bulge).unwrap();
+    assert_eq!(quarter.direction, Some(Orientation::Clockwise));
+    assert!(close_equal(arc_radius(&quarter), 2.0_f64.sqrt(), None));
+    assert!(points_equal(quarter.center, XY { x: 1.0, y: -1.0 }, None));
+    assert!(close_equal(arc_bulge(&quarter), bulge, None));
+
+    assert!(arc_from_bulge(p1, p2, 0.0).is_err());
+  }
+
+  #[test]
+  fn test_arc_from_center() {
+    let origin = XY { x: 0.0, y: 0.0 };
+    let arc = arc_from_center(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 1.0 }, origin, None, Some(Orientation::Clockwise)).unwrap();
+    assert_eq!(arc.direction, Some(Orientation::Clockwise));
+
+    assert_eq!(
+      arc_from_center(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 2.0 }, origin, None, None).unwrap_err(),
+      GeometryError::InconsistentRadii { r1: 1.0, r2: 2.0 }
+    );
+    assert_eq!(
+      arc_from_center(XY { x: 1.0, y: 0.0 }, XY { x: 1.0, y: 0.0 }, origin, None, None).unwrap_err(),
+      GeometryError::CoincidentPoints
+    );
+  }
+
+  #[test]
+  fn test_arc_from_radius() {
+    let p1 = XY { x: 0.0, y: 0.0 };
+    let p2 = XY { x: 2.0, y: 0.0 };
+    let r = 2.0_f64.sqrt();
+
+    let minor = arc_from_radius(p1, p2, r, Orientation::CounterClockwise, false).unwrap();
+    assert!(points_equal(minor.center, XY { x: 1.0, y: 1.0 }, None));
+    assert!(close_equal(arc_sweep_angle(&minor), 0.5 * PI, None));
+
+    let major = arc_from_radius(p1, p2, r, Orientation::CounterClockwise, true).unwrap();
+    assert!(points_equal(major.center, XY { x: 1.0, y: -1.0 }, None));
+    assert!(close_equal(arc_sweep_angle(&major), 1.5 * PI, None));
+
+    assert_eq!(
+      arc_from_radius(p1, p2, 0.5, Orientation::Clockwise, false),
+      Err(GeometryError::RadiusTooSmall { radius: 0.5, minimum: 1.0 })
+    );
+    assert_eq!(
+      arc_from_radius(p1, p2, -1.0, Orientation::Clockwise, false),
+      Err(GeometryError::InvalidRadius { radius: -1.0 })
+    );
+  }
+
+  #[test]
+  fn test_arc_center_angles_round_trip() {
+    let center = XY { x: 1.0, y: 2.0 };
+    let arc = arc_from_center_angles(center, 3.0, 0.25 * PI, -1.25 * PI).unwrap();
+    assert_eq!(arc.direction, Some(Orientation::Clockwise));
+    assert_eq!(arc.major, Some(true));
+
+    let (c, radius, start, sweep) = arc_center_angles(&arc);
+    assert!(points_equal(c, center, None));
+    assert!(close_equal(radius, 3.0, None));
+    assert!(close_equal(start, 0.25 * PI, None));
+    assert!(close_equal(sweep, -1.25 * PI, None));
+
+    assert!(arc_from_center_angles(center, 3.0, 0.0, 2.0 * PI).is_err());
+    assert!(arc_from_center_angles(center, 0.0, 0.0, PI).is_err());
+
+    let in_degrees = arc_from_center_angles(center, 3.0, Degrees(45.0), Degrees(-225.0)).unwrap();
+    assert!(points_equal(in_degrees.p1, arc.p1, None));
+    assert!(points_equal(in_degrees.p2, arc.p2, None));
+  }
+}
+
+#[cfg(test)]
+mod validation_tests {
+
+use super::*;
+
+  fn arc(p1: XY, p2: XY, center: XY) -> Stroke {
+    Stroke::Arc(Arc { p1, p2, center, major: None, direction: None })
+  }
+
+  #[test]
+  fn test_validate_stroke() {
+    let origin = XY { x: 0.0, y: 0.0 };
+    assert_eq!(validate_stroke(&arc(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 1.0 }, origin), None), Ok(()));
+
+    assert_eq!(
+      validate_stroke(&arc(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 2.0 }, origin), None),
+      Err(GeometryError::InconsistentRadii { r1: 1.0, r2: 2.0 })
+    );
+    assert_eq!(
+      validate_stroke(&arc(XY { x: 1.0, y: 0.0 }, XY { x: 1.0, y: 0.0 }, origin), None),
+      Err(GeometryError::CoincidentPoints)
+    );
+    assert_eq!(
+      validate_stroke(&arc(origin, XY { x: 1.0, y: 0.0 }, origin), None),
+      Err(GeometryError::InvalidRadius { radius: 0.0 })
+    );
+    assert_eq!(
+      validate_stroke(&arc(XY { x: f64::NAN, y: 0.0 }, XY { x: 1.0, y: 0.0 }, origin), None),
+      Err(GeometryError::NonFiniteCoordinate)
+    );
+
+    let segment = Stroke::Segment(Segment { p1: origin, p2: XY { x: f64::INFINITY, y: 0.0 } });
+    assert_eq!(validate_stroke(&segment, None), Err(GeometryError::NonFiniteCoordinate));
+  }
+
+  #[test]
+  fn test_validate_stroke_scales_tolerance() {
+    // Radii differ by 1mm on a kilometre-scale drawing.
+    let center = XY { x: 500000.0, y: 500000.0 };
+    let far = arc(XY { x: 500100.0, y: 500000.0 }, XY { x: 500000.0, y: 500100.001 }, center);
+
+    assert!(validate_stroke(&far, None).is_err());
+    assert_eq!(validate_stroke(&far, Tolerance::new(0.000001, 0.00000001)), Ok(()));
+    assert!(validate_stroke(&far, Tolerance::default()).is_err());
+  }
+
+  #[test]
+  fn test_normalize_stroke_snaps_center() {
+    // Center slightly off the bisector of the chord from (1, 0) to (-1, 0).
+    let skewed = arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.001, y: -0.5 });
+
+    let normalized = normalize_stroke(&skewed).unwrap();
+    assert!(points_equal(normalized.get_center().unwrap(), XY { x: 0.0, y: -0.5 }, None));
+    assert_eq!(normalized.get_major(), Some(false));
+    assert_eq!(validate_stroke(&normalized, None), Ok(()));
+    assert_eq!(arc_direction(&normalized), arc_direction(&skewed));
+  }
+
+  #[test]
+  fn test_normalize_half_circle_pins_direction() {
+    // Snapped onto the bisector this becomes an exact half circle, so its
+    // direction can no longer be implied by the major flag.
+    let half = arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0001, y: 0.0 });
+
+    let normalized = normalize_stroke(&half).unwrap();
+    assert!(points_equal(normalized.get_center().unwrap(), XY { x: 0.0, y: 0.0 }, None));
+    assert_eq!(normalized.get_direction(), Some(Orientation::CounterClockwise));
+    assert_eq!(normalized.get_major(), Some(false));
+
+    // Arcs that are not half circles keep relying on the major flag.
+    let skewed = arc(XY { x: 1.0, y: 0.0 }, XY { x: -1.0, y: 0.0 }, XY { x: 0.0001, y: 0.5 });
+    assert_eq!(normalize_stroke(&skewed).unwrap().get_direction(), None);
+
+    let coincident = arc(XY { x: 1.0, y: 0.0 }, XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 0.0 });
+    assert_eq!(normalize_stroke(&coincident), Err(GeometryError::CoincidentPoints));
+  }
+}
+
+#[cfg(test)]
+mod tessellation_tests {
+
+use super::*;
+
+  #[test]
+  fn test_tessellate_arc_by_angle() {
+    let points = tessellate_arc(&quarter_arc(10.0), Some(TessellateOpts { max_deviation: None, max_angle: Some(PI / 8.0) }));
+    assert_eq!(points.len(), 5);
+    assert_eq!(points[0], XY { x: 10.0, y: 0.0 });
+    assert_eq!(points[4], XY { x: 0.0, y: 10.0 });
+    assert!(points_equal(points[2], XY { x: 50.0_f64.sqrt(), y: 50.0_f64.sqrt() }, None));
+  }
+
+  #[test]
+  fn test_tessellate_arc_by_deviation() {
+    let deviation = 0.01;
+    let points = tessellate_arc(&quarter_arc(10.0), Some(TessellateOpts { max_deviation: Some(Tolerance::absolute(deviation)), max_angle: None }));
+
+    // Every chord midpoint must stay within the deviation of the circle.
+    for chord in points.windows(2) {
+      let mid = XY { x: 0.5 * (chord[0].x + chord[1].x), y: 0.5 * (chord[0].y + chord[1].y) };
+      assert!(10.0 - (mid.x * mid.x + mid.y * mid.y).sqrt() <= deviation);
+    }
+
+    let coarser = tessellate_arc(&quarter_arc(10.0), Some(TessellateOpts { max_deviation: Some(Tolerance::absolute(0.1)), max_angle: None }));
+    assert!(coarser.len() < points.len());
+  }
+
+  #[test]
+  fn test_relative_deviation_scales_with_radius() {
+    let opts = Some(TessellateOpts { max_deviation: Some(Tolerance::new(0.0, 0.001)), max_angle: None });
+    let count = arc_tessellation_count(&quarter_arc(10.0), opts);
+    assert_eq!(arc_tessellation_count(&quarter_arc(10000.0), opts), count);
+
+    let absolute = Some(TessellateOpts { max_deviation: Some(Tolerance::absolute(0.01)), max_angle: None });
+    assert_eq!(arc_tessellation_count(&quarter_arc(10.0), absolute), count);
+    assert!(arc_tessellation_count(&quarter_arc(10000.0), absolute) > count);
+  }
+
+  #[test]
+  fn test_tessellation_count_is_capped() {
+    let opts = Some(TessellateOpts { max_deviation: Some(Tolerance::absolute(1e-12)), max_angle: Some(1e-9) });
+    assert_eq!(arc_tessellation_count(&quarter_arc(10.0), opts), MAX_TESSELLATION_COUNT);
+    assert_eq!(tessellate_arc(&quarter_arc(10.0), opts).len(), MAX_TESSELLATION_COUNT + 1);
+
+    // Past the cap the deviation limit is no longer met.
+    let radius = 1000000000.0;
+    let deviation = 0.000001;
+    let opts = Some(TessellateOpts { max_deviation: Some(Tolerance::absolute(deviation)), max_angle: None });
+    let points = tessellate_arc(&quarter_arc(radius), opts);
+    assert_eq!(points.len(), MAX_TESSELLATION_COUNT + 1);
+    let mid = XY { x: 0.5 * (points[0].x + points[1].x), y: 0.5 * (points[0].y + points[1].y) };
+    assert!(radius - (mid.x * mid.x + mid.y * mid.y).sqrt() > deviation);
+  }
+
+  #[test]
+  fn test_flatten_strokes() {
+    let segment = Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } });
+    assert_eq!(flatten_stroke(&segment, None), vec![Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }]);
+
+    let annotated = AnnotatedStroke { stroke: Stroke::Arc(quarter_arc(10.0)), data: 7 };
+    let pieces = flatten_annotated_stroke(&annotated, Some(TessellateOpts { max_deviation: None, max_angle: Some(PI / 6.0) }));
+    assert_eq!(pieces.len(), 3);
+    assert!(pieces.iter().all(|piece| piece.data == 7 && matches!(piece.stroke, Stroke::Segment(_))));
+    assert_eq!(pieces[0].get_p2(), pieces[1].get_p1());
+  }
+}
@@ -0,0 +1,126 @@
+use std::f64::consts::PI;
+use rust_comp_geo::geometry::point::point::points_equal;
+use rust_comp_geo::geometry::stroke::arc::{arc_direction, arc_length};
+use rust_comp_geo::geometry::transform::transform::{transform_path, Transform2D, Transformable};
+use rust_comp_geo::geometry::types::error::GeometryError;
+use rust_comp_geo::geometry::types::stroke_types::{AnnotatedStroke, Arc, Segment, Stroke};
+use rust_comp_geo::geometry::types::types::{BoundingBox, Circle, Orientation, Vector2D, XY};
+use rust_comp_geo::utils::utils::close_equal;
+use super::helpers::quarter_arc;
+
+#[cfg(test)]
+mod transform_tests {
+
+use super::*;
+
+  #[test]
+  fn test_transform_point() {
+    let p = XY { x: 1.0, y: 0.0 };
+
+    assert_eq!(p.transform(&Transform2D::translation(2.0, 3.0)).unwrap(), XY { x: 3.0, y: 3.0 });
+    assert!(points_equal(p.transform(&Transform2D::rotation(PI / 2.0)).unwrap(), XY { x: 0.0, y: 1.0 }, None));
+
+    let about = Transform2D::rotation_about(XY { x: 1.0, y: 1.0 }, PI);
+    assert!(points_equal(p.transform(&about).unwrap(), XY { x: 1.0, y: 2.0 }, None));
+
+    let scaled = Transform2D::scale_about(XY { x: 1.0, y: 1.0 }, 2.0, 3.0);
+    assert!(points_equal(XY { x: 2.0, y: 2.0 }.transform(&scaled).unwrap(), XY { x: 3.0, y: 4.0 }, None));
+
+    let mirror = Transform2D::mirror(XY { x: 0.0, y: 0.0 }, XY { x: 1.0, y: 1.0 });
+    assert!(points_equal(XY { x: 2.0, y: 0.0 }.transform(&mirror).unwrap(), XY { x: 0.0, y: 2.0 }, None));
+    assert!(mirror.is_mirroring());
+  }
+
+  #[test]
+  fn test_transform_vector_ignores_translation() {
+    let v = Vector2D { i: 1.0, j: 0.0 };
+    let t = Transform2D::translation(5.0, 5.0).then(&Transform2D::rotation(PI / 2.0));
+    let moved = v.transform(&t).unwrap();
+    assert!(close_equal(moved.i, 0.0, None));
+    assert!(close_equal(moved.j, 1.0, None));
+  }
+
+  #[test]
+  fn test_composition_order() {
+    let p = XY { x: 1.0, y: 0.0 };
+    let rotate_then_move = Transform2D::rotation(PI / 2.0).then(&Transform2D::translation(1.0, 0.0));
+    let move_then_rotate = Transform2D::translation(1.0, 0.0).then(&Transform2D::rotation(PI / 2.0));
+
+    assert!(points_equal(p.transform(&rotate_then_move).unwrap(), XY { x: 1.0, y: 1.0 }, None));
+    assert!(points_equal(p.transform(&move_then_rotate).unwrap(), XY { x: 0.0, y: 2.0 }, None));
+    assert_eq!(Transform2D::default(), Transform2D::identity());
+  }
+
+  #[test]
+  fn test_transform_segment_and_annotated_stroke() {
+    let stroke = AnnotatedStroke {
+      stroke: Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      data: "edge"
+    };
+    let moved = stroke.transform(&Transform2D::scale_xy(2.0, 1.0)).unwrap();
+
+    assert_eq!(moved.data, "edge");
+    assert_eq!(moved.stroke, Stroke::Segment(Segment { p1: XY { x: 0.0, y: 0.0 }, p2: XY { x: 2.0, y: 0.0 } }));
+  }
+
+  #[test]
+  fn test_transform_arc() {
+    let arc = quarter_arc(1.0);
+    let scaled = arc.transform(&Transform2D::scale(2.0)).unwrap();
+    assert!(close_equal(arc_length(&scaled), PI, None));
+    assert_eq!(arc_direction(&scaled), Orientation::CounterClockwise);
+
+    assert_eq!(arc.transform(&Transform2D::scale_xy(2.0, 1.0)), Err(GeometryError::NonUniformScale));
+    assert_eq!(arc.transform(&Transform2D::scale(0.0)), Err(GeometryError::SingularTransform));
+  }
+
+  #[test]
+  fn test_mirror_arc_flips_direction() {
+    let mirror = Transform2D::scale_xy(1.0, -1.0);
+
+    let implied = quarter_arc(1.0).transform(&mirror).unwrap();
+    assert_eq!(implied.direction, None);
+    assert_eq!(arc_direction(&implied), Orientation::Clockwise);
+
+    let explicit = Arc { direction: Some(Orientation::CounterClockwise), ..quarter_arc(1.0) };
+    assert_eq!(explicit.transform(&mirror).unwrap().direction, Some(Orientation::Clockwise));
+
+    let half = Arc {
+      p1: XY { x: 1.0, y: 0.0 },
+      p2: XY { x: -1.0, y: 0.0 },
+      center: XY { x: 0.0, y: 0.0 },
+      major: None,
+      direction: None
+    };
+    let mirrored = half.transform(&Transform2D::scale_xy(-1.0, 1.0)).unwrap();
+    assert_eq!(arc_direction(&half), Orientation::CounterClockwise);
+    assert_eq!(arc_direction(&mirrored), Orientation::Clockwise);
+  }
+
+  #[test]
+  fn test_transform_circle_and_box() {
+    let circle = Circle { center: XY { x: 1.0, y: 0.0 }, radius: 1.0 };
+    let moved = circle.transform(&Transform2D::rotation(PI / 2.0).then(&Transform2D::scale(3.0))).unwrap();
+    assert!(points_equal(moved.center, XY { x: 0.0, y: 3.0 }, None));
+    assert!(close_equal(moved.radius, 3.0, None));
+    assert!(circle.transform(&Transform2D::scale_xy(1.0, 2.0)).is_err());
+
+    let bounds = BoundingBox { x_min: -1.0, x_max: 1.0, y_min: -1.0, y_max: 1.0 };
+    let rotated = bounds.transform(&Transform2D::rotation(PI / 4.0)).unwrap();
+    assert!(close_equal(rotated.x_max, 2.0_f64.sqrt(), None));
+    assert!(close_equal(rotated.y_min, -(2.0_f64.sqrt()), None));
+  }
+
+  #[test]
+  fn test_transform_path() {
+    let path = vec![
+      Stroke::Segment(Segment { p1: XY { x: 0.0, y: 1.0 }, p2: XY { x: 1.0, y: 0.0 } }),
+      Stroke::Arc(quarter_arc(1.0)),
+    ];
+
+    let moved = transform_path(&path, &Transform2D::translation(1.0, 1.0)).unwrap();
+    assert_eq!(moved.len(), 2);
+    assert_eq!(moved[0], Stroke::Segment(Segment { p1: XY { x: 1.0, y: 2.0 }, p2: XY { x: 2.0, y: 1.0 } }));
+    assert_eq!(transform_path(&path, &Transform2D::scale_xy(1.0, 2.0)), Err(GeometryError::NonUniformScale));
+  }
+}
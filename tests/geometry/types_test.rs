@@ -1,6 +1,8 @@
 use serde::{Serialize, Deserialize};
-use rust_comp_geo::geometry::types::stroke_types::{Arc, AnnotatedStroke, Segment, Stroke};
-use rust_comp_geo::geometry::types::types::XY;
+use rust_comp_geo::geometry::types::stroke_types::{Arc, ArcBehavior, AnnotatedStroke, Segment, SegmentBehavior, Stroke};
+use rust_comp_geo::geometry::stroke::arc::arc_direction;
+use rust_comp_geo::geometry::types::error::GeometryError;
+use rust_comp_geo::geometry::types::types::{BoundingBox, Circle, Orientation, XY};
 
 #[cfg(test)]
 mod type_tests {
@@ -24,7 +26,8 @@ use super::*;
       p1: XY { x: 0.0, y: 0.0 },
       p2: XY { x: 1.0, y: 1.0 },
       center: XY { x: 0.5, y: 0.5 },
-      major: Some(true)
+      major: Some(true),
+      direction: None
     };
 
     // Cast to stroke.
@@ -106,7 +109,8 @@ use super::*;
         p1: XY { x: 0.0, y: 0.0 },
         p2: XY { x: 1.0, y: 1.0 },
         center: XY { x: 0.5, y: 0.5 },
-        major: Some(true)
+        major: Some(true),
+        direction: None
       }),
       data: StrokeData {
         name: "strokeName".to_string(),
@@ -147,4 +151,79 @@ use super::*;
       }
     }
   }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod arc_behavior_tests {
+
+use super::*;
+
+  // An arc type written before directions existed.
+  #[derive(Clone)]
+  struct LegacyArc {
+    p1: XY,
+    p2: XY,
+    center: XY,
+  }
+
+  impl SegmentBehavior for LegacyArc {
+    fn get_p1(&self) -> XY { self.p1 }
+    fn get_p2(&self) -> XY { self.p2 }
+    fn set_p1(&mut self, p: XY) { self.p1 = p; }
+    fn set_p2(&mut self, p: XY) { self.p2 = p; }
+  }
+
+  impl ArcBehavior for LegacyArc {
+    fn get_center(&self) -> Option<XY> { Some(self.center) }
+    fn get_major(&self) -> Option<bool> { Some(false) }
+    fn set_center(&mut self, p: XY) { self.center = p; }
+    fn set_major(&mut self, _major: bool) {}
+  }
+
+  #[test]
+  fn test_direction_defaults() {
+    let mut arc = LegacyArc { p1: XY { x: 0.0, y: 1.0 }, p2: XY { x: 1.0, y: 0.0 }, center: XY { x: 0.0, y: 0.0 } };
+    arc.set_direction(Orientation::CounterClockwise);
+    assert_eq!(arc.get_direction(), None);
+    assert_eq!(arc_direction(&arc), Orientation::Clockwise);
+  }
+}
+
+#[cfg(test)]
+mod constructor_tests {
+
+use super::*;
+
+  #[test]
+  fn test_circle_try_new() {
+    let center = XY { x: 1.0, y: 2.0 };
+    let circle = Circle::try_new(center, 3.0).unwrap();
+    assert_eq!((circle.center, circle.radius), (center, 3.0));
+    assert!(Circle::try_new(center, 0.0).is_ok());
+
+    assert_eq!(Circle::try_new(center, -1.0).unwrap_err(), GeometryError::InvalidRadius { radius: -1.0 });
+    assert!(matches!(Circle::try_new(center, f64::NAN), Err(GeometryError::InvalidRadius { .. })));
+    assert_eq!(Circle::try_new(XY { x: f64::INFINITY, y: 0.0 }, 1.0).unwrap_err(), GeometryError::NonFiniteCoordinate);
+  }
+
+  #[test]
+  fn test_bounding_box_try_new() {
+    assert!(BoundingBox::try_new(0.0, 1.0, 0.0, 2.0).is_ok());
+    assert!(BoundingBox::try_new(1.0, 1.0, 2.0, 2.0).is_ok());
+    assert!(BoundingBox::try_new(f64::NEG_INFINITY, f64::INFINITY, 0.0, 0.0).is_ok());
+
+    assert_eq!(
+      BoundingBox::try_new(2.0, 1.0, 0.0, 1.0).unwrap_err(),
+      GeometryError::InvalidBounds { x_min: 2.0, x_max: 1.0, y_min: 0.0, y_max: 1.0 }
+    );
+    assert!(BoundingBox::try_new(0.0, 1.0, 1.0, 0.0).is_err());
+    assert!(BoundingBox::try_new(0.0, f64::NAN, 0.0, 1.0).is_err());
+  }
+
+  #[test]
+  fn test_arc_new() {
+    let arc = Arc::new(XY { x: 1.0, y: 0.0 }, XY { x: 0.0, y: 1.0 }, XY { x: 0.0, y: 0.0 }, Some(true));
+    assert_eq!(arc.major, Some(true));
+    assert_eq!(arc.direction, None);
+  }
+}
@@ -0,0 +1,129 @@
+use std::f64::consts::PI;
+use rust_comp_geo::geometry::types::types::{Vector2D, Vector3D, XY, XYZ};
+use rust_comp_geo::utils::utils::close_equal;
+
+#[cfg(test)]
+mod vector_2d_tests {
+
+use super::*;
+
+  fn vectors_equal(v1: Vector2D, v2: Vector2D) -> bool {
+    close_equal(v1.i, v2.i, None) && close_equal(v1.j, v2.j, None)
+  }
+
+  #[test]
+  fn test_point_operators() {
+    let p1 = XY { x: 1.0, y: 2.0 };
+    let p2 = XY { x: 4.0, y: 6.0 };
+    let v = p2 - p1;
+
+    assert_eq!(v, Vector2D { i: 3.0, j: 4.0 });
+    assert_eq!(Vector2D::between(p1, p2), v);
+    assert_eq!(p1 + v, p2);
+    assert_eq!(p2 - v, p1);
+
+    let mut p = p1;
+    p += v;
+    assert_eq!(p, p2);
+    p -= v;
+    assert_eq!(p, p1);
+  }
+
+  #[test]
+  fn test_vector_operators() {
+    let v1 = Vector2D::new(1.0, 2.0);
+    let v2 = Vector2D::new(3.0, -1.0);
+
+    assert_eq!(v1 + v2, Vector2D::new(4.0, 1.0));
+    assert_eq!(v1 - v2, Vector2D::new(-2.0, 3.0));
+    assert_eq!(-v1, Vector2D::new(-1.0, -2.0));
+    assert_eq!(v1 * 2.0, Vector2D::new(2.0, 4.0));
+    assert_eq!(2.0 * v1, Vector2D::new(2.0, 4.0));
+    assert_eq!(v1 / 2.0, Vector2D::new(0.5, 1.0));
+  }
+
+  #[test]
+  fn test_products_and_length() {
+    let v1 = Vector2D::new(3.0, 4.0);
+    let v2 = Vector2D::new(-4.0, 3.0);
+
+    assert_eq!(v1.dot(v2), 0.0);
+    assert_eq!(v1.cross(v2), 25.0);
+    assert_eq!(v2.cross(v1), -25.0);
+    assert_eq!(v1.length(), 5.0);
+    assert_eq!(v1.length_squared(), 25.0);
+    assert!(vectors_equal(v1.normalize().unwrap(), Vector2D::new(0.6, 0.8)));
+    assert_eq!(Vector2D::zero().normalize(), None);
+  }
+
+  #[test]
+  fn test_rotation() {
+    let v = Vector2D::new(1.0, 0.0);
+
+    assert_eq!(v.perp(), Vector2D::new(0.0, 1.0));
+    assert!(vectors_equal(v.rotate(PI / 2.0), v.perp()));
+    assert!(vectors_equal(v.rotate(PI), Vector2D::new(-1.0, 0.0)));
+    assert!(vectors_equal(Vector2D::from_angle(PI / 4.0).rotate(-PI / 4.0), v));
+    assert!(close_equal(Vector2D::new(0.0, -2.0).angle(), -PI / 2.0, None));
+  }
+
+  #[test]
+  fn test_projection_and_angles() {
+    let v = Vector2D::new(2.0, 2.0);
+    let axis = Vector2D::new(5.0, 0.0);
+
+    assert_eq!(v.project_onto(axis), Some(Vector2D::new(2.0, 0.0)));
+    assert_eq!(v.project_onto(Vector2D::zero()), None);
+
+    assert!(close_equal(axis.angle_between(v), PI / 4.0, None));
+    assert!(close_equal(v.angle_between(axis), PI / 4.0, None));
+    assert!(close_equal(axis.signed_angle_to(v), PI / 4.0, None));
+    assert!(close_equal(v.signed_angle_to(axis), -PI / 4.0, None));
+    assert!(close_equal(axis.angle_between(-axis), PI, None));
+    assert_eq!(axis.angle_between(Vector2D::new(0.0, 0.0)), 0.0);
+  }
+}
+
+#[cfg(test)]
+mod vector_3d_tests {
+
+use super::*;
+
+  #[test]
+  fn test_point_operators() {
+    let p1 = XYZ { x: 1.0, y: 2.0, z: 3.0 };
+    let p2 = XYZ { x: 2.0, y: 4.0, z: 6.0 };
+    let v = p2 - p1;
+
+    assert_eq!(v, Vector3D::new(1.0, 2.0, 3.0));
+    assert_eq!(p1 + v, p2);
+    assert_eq!(p2 - v, p1);
+    assert_eq!(2.0 * v - v, v);
+    assert_eq!(-v / 2.0, Vector3D::new(-0.5, -1.0, -1.5));
+  }
+
+  #[test]
+  fn test_products() {
+    let x = Vector3D::new(1.0, 0.0, 0.0);
+    let y = Vector3D::new(0.0, 1.0, 0.0);
+
+    assert_eq!(x.cross(y), Vector3D::new(0.0, 0.0, 1.0));
+    assert_eq!(y.cross(x), Vector3D::new(0.0, 0.0, -1.0));
+    assert_eq!(x.dot(y), 0.0);
+    assert_eq!(Vector3D::new(2.0, 3.0, 6.0).length(), 7.0);
+    assert_eq!(Vector3D::new(0.0, 0.0, 4.0).normalize(), Some(Vector3D::new(0.0, 0.0, 1.0)));
+    assert_eq!(Vector3D::zero().normalize(), None);
+  }
+
+  #[test]
+  fn test_projection_and_angles() {
+    let v = Vector3D::new(1.0, 1.0, 1.0);
+    let z = Vector3D::new(0.0, 0.0, 3.0);
+
+    assert_eq!(v.project_onto(z), Some(Vector3D::new(0.0, 0.0, 1.0)));
+    assert_eq!(v.project_onto(Vector3D::zero()), None);
+    assert!(close_equal(z.angle_between(Vector3D::new(1.0, 0.0, 1.0)), PI / 4.0, None));
+    assert!(close_equal(z.angle_between(-z), PI, None));
+    assert_eq!(z.angle_between(Vector3D::new(0.0, 0.0, 0.0)), 0.0);
+  }
+}
@@ -1,4 +1,5 @@
 use rust_comp_geo::{
+  geometry::types::error::GeometryError,
   geometry::types::types::{BoundingBox, XY}, 
   quadtree::{
     quadtree::{Quadtree, QuadtreeProps}, 
@@ -135,4 +136,40 @@ fn setup() -> Quadtree<QuadtreePoint<String>, String> {
     assert_eq!(quadtree.objects.len(), 0);
     assert_eq!(quadtree.nodes.len(), 0);
   }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod quadtree_constructor_tests {
+
+use super::*;
+
+  fn bounds() -> BoundingBox {
+    BoundingBox { x_min: 0.0, x_max: 100.0, y_min: 0.0, y_max: 100.0 }
+  }
+
+  #[test]
+  fn test_props_try_new() {
+    let props = QuadtreeProps::try_new(bounds(), 2, 4).unwrap();
+    assert_eq!((props.max_objects, props.max_levels), (2, 4));
+
+    assert_eq!(
+      QuadtreeProps::try_new(bounds(), 0, 4).err(),
+      Some(GeometryError::InvalidQuadtreeProps { max_objects: 0, max_levels: 4 })
+    );
+    assert!(QuadtreeProps::try_new(bounds(), 2, 0).is_err());
+
+    let inverted = BoundingBox { x_min: 100.0, x_max: 0.0, y_min: 0.0, y_max: 100.0 };
+    assert!(matches!(QuadtreeProps::try_new(inverted, 2, 4), Err(GeometryError::InvalidBounds { .. })));
+  }
+
+  #[test]
+  fn test_quadtree_try_new() {
+    let props = QuadtreeProps { bounds: bounds(), max_objects: 2, max_levels: 4 };
+    let quadtree: Quadtree<QuadtreePoint<String>, String> = Quadtree::try_new(props, 0).unwrap();
+    assert_eq!(quadtree.max_objects, 2);
+
+    let props = QuadtreeProps { bounds: bounds(), max_objects: 0, max_levels: 4 };
+    let result: Result<Quadtree<QuadtreePoint<String>, String>, GeometryError> = Quadtree::try_new(props, 0);
+    assert!(result.is_err());
+  }
+}
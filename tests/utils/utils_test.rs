@@ -1,4 +1,4 @@
-use rust_comp_geo::utils::utils::{close_equal, round, round_to_n_decimals};
+use rust_comp_geo::utils::utils::{close_equal, round, round_to_n_decimals, round_within, Tolerance};
 
 #[cfg(test)] // Only compiles when running tests
 mod close_equal_test {
@@ -76,4 +76,51 @@ mod close_equal_test {
     }
   }
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tolerance_test {
+  use super::*;
+
+  #[test]
+  fn test_default_matches_close_equal() {
+      let tolerance = Tolerance::default();
+      for (a, b) in [(10.0, 9.9999999999), (10.0, 9.998), (0.0, -0.0)] {
+          assert_eq!(close_equal(a, b, tolerance), close_equal(a, b, None));
+      }
+  }
+
+  #[test]
+  fn test_conversions() {
+      assert_eq!(Tolerance::from(None), Tolerance::default());
+      assert_eq!(Tolerance::from(Some(0.5)), Tolerance::absolute(0.5));
+      let tolerance = Tolerance::new(0.1, 0.01);
+      assert_eq!(Tolerance::from(&tolerance), tolerance);
+      assert!(close_equal(1.0, 1.05, Some(0.1)));
+      assert!(close_equal(1.0, 1.05, 0.1));
+      assert!(close_equal(1.0, 1.05, tolerance));
+  }
+
+  #[test]
+  fn test_relative_scales_with_magnitude() {
+      let tolerance = Tolerance::new(0.000001, 0.000000001);
+
+      // A kilometre-scale coordinate allows a larger absolute difference.
+      assert!(close_equal(1000000.0, 1000000.0005, tolerance));
+      assert!(!close_equal(1.0, 1.0005, tolerance));
+      assert!(!close_equal(1000000.0, 1000000.01, tolerance));
+      assert_eq!(tolerance.at_scale(-1000000.0), 0.001);
+      assert_eq!(tolerance.at_scale(1.0), 0.000001);
+  }
+
+  #[test]
+  fn test_round_within() {
+      assert_eq!(Tolerance::absolute(0.001).decimals(), 3);
+      assert_eq!(Tolerance::absolute(0.005).decimals(), 3);
+      assert_eq!(Tolerance::absolute(10.0).decimals(), 0);
+      assert_eq!(Tolerance::default().decimals(), 6);
+      assert_eq!(round_within(1.23456, Tolerance::absolute(0.01)), 1.23);
+      assert_eq!(round_within(4.815162342, Tolerance::default()), round(4.815162342));
+      assert_eq!(Tolerance::from(0.5), Tolerance::absolute(0.5));
+  }
+}